@@ -0,0 +1,101 @@
+//! In-memory cache of a provider's JWKS: refreshed on a TTL, and re-fetched
+//! once on a `kid` miss to pick up provider key rotation between refreshes.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Result};
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use tokio::sync::RwLock;
+
+const DEFAULT_TTL: Duration = Duration::from_secs(300);
+
+struct CachedJwks {
+    keys: JwkSet,
+    fetched_at: Instant,
+}
+
+/// Caches a single authority's JWKS. [`JwksCache::find`] serves from cache
+/// while the TTL hasn't elapsed and the `kid` is known; otherwise it
+/// refetches once before giving up, so a provider rotating its signing
+/// keys doesn't require restarting the server.
+pub struct JwksCache {
+    jwks_uri: String,
+    ttl: Duration,
+    cached: RwLock<Option<CachedJwks>>,
+}
+
+impl JwksCache {
+    pub fn new(jwks_uri: String) -> Self {
+        Self::with_ttl(jwks_uri, DEFAULT_TTL)
+    }
+
+    pub fn with_ttl(jwks_uri: String, ttl: Duration) -> Self {
+        Self { jwks_uri, ttl, cached: RwLock::new(None) }
+    }
+
+    /// Find the key matching `kid`, refetching the JWKS once if the cache
+    /// is stale or doesn't (yet) contain it.
+    pub async fn find(&self, kid: &str) -> Result<Jwk> {
+        if let Some(jwk) = self.lookup(kid).await {
+            return Ok(jwk);
+        }
+        self.refresh().await?;
+        self.lookup(kid)
+            .await
+            .ok_or_else(|| anyhow!("no JWKS key matching kid '{kid}'"))
+    }
+
+    async fn lookup(&self, kid: &str) -> Option<Jwk> {
+        let cached = self.cached.read().await;
+        let entry = cached.as_ref()?;
+        if entry.fetched_at.elapsed() > self.ttl {
+            return None;
+        }
+        entry.keys.find(kid).cloned()
+    }
+
+    async fn refresh(&self) -> Result<()> {
+        let keys: JwkSet = reqwest::get(&self.jwks_uri)
+            .await
+            .context("failed to fetch JWKS")?
+            .json()
+            .await
+            .context("failed to parse JWKS response")?;
+        *self.cached.write().await = Some(CachedJwks { keys, fetched_at: Instant::now() });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::jwk::{AlgorithmParameters, CommonParameters, OctetKeyParameters, OctetKeyType};
+
+    fn octet_jwk(kid: &str) -> Jwk {
+        Jwk {
+            common: CommonParameters { key_id: Some(kid.to_string()), ..Default::default() },
+            algorithm: AlgorithmParameters::OctetKey(OctetKeyParameters {
+                key_type: OctetKeyType::Octet,
+                value: "c2VjcmV0".to_string(),
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_expired_entry_is_not_served_from_cache() {
+        let cache = JwksCache::with_ttl("http://127.0.0.1:0/jwks".to_string(), Duration::from_secs(0));
+        *cache.cached.write().await =
+            Some(CachedJwks { keys: JwkSet { keys: vec![octet_jwk("key-1")] }, fetched_at: Instant::now() });
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(cache.lookup("key-1").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fresh_cache_serves_known_kid_without_refetch() {
+        let cache = JwksCache::with_ttl("http://127.0.0.1:0/jwks".to_string(), Duration::from_secs(300));
+        *cache.cached.write().await =
+            Some(CachedJwks { keys: JwkSet { keys: vec![octet_jwk("key-1")] }, fetched_at: Instant::now() });
+        assert!(cache.lookup("key-1").await.is_some());
+        assert!(cache.lookup("missing").await.is_none());
+    }
+}
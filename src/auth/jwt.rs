@@ -0,0 +1,91 @@
+//! Bearer JWT validation: selects a verifier by the token's `alg`/`kid`
+//! header, resolves the signing key from the cached JWKS, and checks the
+//! signature and audience before the token's claims are trusted.
+
+use anyhow::{anyhow, bail, Context, Result};
+use jsonwebtoken::{decode, decode_header, DecodingKey, Validation};
+use serde::Deserialize;
+
+use super::AuthConfig;
+
+/// Validate `token` as a bearer JWT against `config`: rejects any `alg` not
+/// in `config.allowed_algorithms`, resolves the signing key by `kid` from
+/// the cached JWKS, and checks the signature and audience. Returns the
+/// scopes granted by the token's `scope`/`scp` claim on success.
+pub async fn validate(config: &AuthConfig, token: &str) -> Result<Vec<String>> {
+    let header = decode_header(token).context("invalid JWT header")?;
+
+    if !config.allowed_algorithms.contains(&header.alg) {
+        bail!("JWT uses disallowed algorithm '{:?}'", header.alg);
+    }
+
+    let kid = header
+        .kid
+        .ok_or_else(|| anyhow!("JWT is missing a 'kid' header"))?;
+    let jwk = config.jwks.find(&kid).await?;
+    let decoding_key =
+        DecodingKey::from_jwk(&jwk).context("failed to build decoding key from JWKS entry")?;
+
+    let mut validation = Validation::new(header.alg);
+    validation.set_audience(&[&config.audience]);
+
+    let data = decode::<Claims>(token, &decoding_key, &validation).context("JWT validation failed")?;
+    Ok(data.claims.granted_scopes())
+}
+
+#[derive(Debug, Deserialize)]
+struct Claims {
+    #[serde(default)]
+    scope: Option<ScopeClaim>,
+    #[serde(default)]
+    scp: Option<ScopeClaim>,
+}
+
+impl Claims {
+    fn granted_scopes(self) -> Vec<String> {
+        let mut scopes = self.scope.map(ScopeClaim::into_scopes).unwrap_or_default();
+        scopes.extend(self.scp.map(ScopeClaim::into_scopes).unwrap_or_default());
+        scopes
+    }
+}
+
+/// The `scope`/`scp` claim, which providers render either as a
+/// space-delimited string (`scope`) or a JSON array (`scp`).
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ScopeClaim {
+    SpaceDelimited(String),
+    List(Vec<String>),
+}
+
+impl ScopeClaim {
+    fn into_scopes(self) -> Vec<String> {
+        match self {
+            ScopeClaim::SpaceDelimited(s) => s.split_whitespace().map(str::to_string).collect(),
+            ScopeClaim::List(scopes) => scopes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_space_delimited_scope_splits_on_whitespace() {
+        let claim: ScopeClaim = serde_json::from_str("\"pets:read pets:write\"").unwrap();
+        assert_eq!(claim.into_scopes(), vec!["pets:read".to_string(), "pets:write".to_string()]);
+    }
+
+    #[test]
+    fn test_list_scope_passes_through() {
+        let claim: ScopeClaim = serde_json::from_str(r#"["pets:read", "pets:write"]"#).unwrap();
+        assert_eq!(claim.into_scopes(), vec!["pets:read".to_string(), "pets:write".to_string()]);
+    }
+
+    #[test]
+    fn test_claims_combine_scope_and_scp() {
+        let claims: Claims = serde_json::from_str(r#"{"scope": "pets:read", "scp": ["pets:write"]}"#).unwrap();
+        assert_eq!(claims.granted_scopes(), vec!["pets:read".to_string(), "pets:write".to_string()]);
+    }
+}
@@ -0,0 +1,181 @@
+//! OAuth2/JWT-based authorization for served MCP tools: validates bearer
+//! tokens against an OAuth authority and enforces per-tool scope
+//! requirements derived from the `OpenAPI` spec.
+
+pub mod jwks;
+pub mod jwt;
+pub mod scopes;
+
+use anyhow::{bail, Result};
+use jsonwebtoken::Algorithm;
+
+use jwks::JwksCache;
+
+/// The signing algorithms this server knows how to verify. `--auth-algorithms`
+/// may restrict acceptance to a subset of these; anything else (including
+/// `none`, which the JWT header doesn't even parse as a known `Algorithm`)
+/// is always rejected.
+pub const SUPPORTED_ALGORITHMS: &[Algorithm] = &[
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+    Algorithm::PS256,
+    Algorithm::EdDSA,
+];
+
+/// Auth settings derived from the `--auth-authority`/`--auth-audience`/
+/// `--auth-jwks-uri`/`--auth-algorithms` CLI flags. A server with no
+/// `AuthConfig` runs unauthenticated.
+pub struct AuthConfig {
+    pub authority: String,
+    pub audience: String,
+    pub jwks_uri: String,
+    pub allowed_algorithms: Vec<Algorithm>,
+    pub(crate) jwks: JwksCache,
+}
+
+impl AuthConfig {
+    /// Build an `AuthConfig` from the CLI flags, or `None` if auth is
+    /// disabled (`authority` not set). Fails if `authority` is set without
+    /// the required `audience`, or if `algorithms` names anything outside
+    /// [`SUPPORTED_ALGORITHMS`].
+    pub fn from_flags(
+        authority: Option<String>,
+        audience: Option<String>,
+        jwks_uri: Option<String>,
+        algorithms: Vec<String>,
+    ) -> Result<Option<Self>> {
+        let Some(authority) = authority else {
+            return Ok(None);
+        };
+        let Some(audience) = audience else {
+            bail!("--auth-audience is required when --auth-authority is set");
+        };
+        let jwks_uri = jwks_uri
+            .unwrap_or_else(|| format!("{}/.well-known/jwks.json", authority.trim_end_matches('/')));
+        let allowed_algorithms = parse_algorithms(&algorithms)?;
+
+        Ok(Some(Self {
+            jwks: JwksCache::new(jwks_uri.clone()),
+            authority,
+            audience,
+            jwks_uri,
+            allowed_algorithms,
+        }))
+    }
+}
+
+/// Parse `--auth-algorithms` entries (e.g. `RS256`, `EdDSA`), defaulting to
+/// every [`SUPPORTED_ALGORITHMS`] entry when none are given. Rejects names
+/// that don't parse as a JWT algorithm or that this server doesn't support
+/// verifying (e.g. `HS256`, `none`).
+fn parse_algorithms(names: &[String]) -> Result<Vec<Algorithm>> {
+    if names.is_empty() {
+        return Ok(SUPPORTED_ALGORITHMS.to_vec());
+    }
+
+    names
+        .iter()
+        .map(|name| {
+            let algorithm: Algorithm = name
+                .parse()
+                .map_err(|_| anyhow::anyhow!("unknown JWT algorithm '{name}'"))?;
+            if !SUPPORTED_ALGORITHMS.contains(&algorithm) {
+                bail!("unsupported JWT algorithm '{name}'");
+            }
+            Ok(algorithm)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_flags_disabled_without_authority() {
+        assert!(AuthConfig::from_flags(None, None, None, Vec::new()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_from_flags_requires_audience() {
+        let result = AuthConfig::from_flags(Some("https://auth.example.com".to_string()), None, None, Vec::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_flags_derives_default_jwks_uri() {
+        let config = AuthConfig::from_flags(
+            Some("https://auth.example.com".to_string()),
+            Some("my-api".to_string()),
+            None,
+            Vec::new(),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(config.jwks_uri, "https://auth.example.com/.well-known/jwks.json");
+    }
+
+    #[test]
+    fn test_from_flags_honors_explicit_jwks_uri() {
+        let config = AuthConfig::from_flags(
+            Some("https://auth.example.com".to_string()),
+            Some("my-api".to_string()),
+            Some("https://auth.example.com/custom-jwks".to_string()),
+            Vec::new(),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(config.jwks_uri, "https://auth.example.com/custom-jwks");
+    }
+
+    #[test]
+    fn test_from_flags_defaults_to_all_supported_algorithms() {
+        let config = AuthConfig::from_flags(
+            Some("https://auth.example.com".to_string()),
+            Some("my-api".to_string()),
+            None,
+            Vec::new(),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(config.allowed_algorithms, SUPPORTED_ALGORITHMS.to_vec());
+    }
+
+    #[test]
+    fn test_from_flags_restricts_to_named_algorithms() {
+        let config = AuthConfig::from_flags(
+            Some("https://auth.example.com".to_string()),
+            Some("my-api".to_string()),
+            None,
+            vec!["ES256".to_string(), "EdDSA".to_string()],
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(config.allowed_algorithms, vec![Algorithm::ES256, Algorithm::EdDSA]);
+    }
+
+    #[test]
+    fn test_from_flags_rejects_unsupported_algorithm() {
+        let result = AuthConfig::from_flags(
+            Some("https://auth.example.com".to_string()),
+            Some("my-api".to_string()),
+            None,
+            vec!["HS256".to_string()],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_flags_rejects_unknown_algorithm_name() {
+        let result = AuthConfig::from_flags(
+            Some("https://auth.example.com".to_string()),
+            Some("my-api".to_string()),
+            None,
+            vec!["none".to_string()],
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,189 @@
+//! Derives each operation's required OAuth2 scopes from its OpenAPI
+//! `security` requirements, and checks a token's granted scopes against
+//! them at call time.
+
+use openapiv3::{OpenAPI, Operation, ReferenceOr, SecurityRequirement, SecurityScheme};
+
+/// Whether an operation declares a `security` requirement at all (its own
+/// `security`, or the document's top-level default when the operation
+/// doesn't override it). `false` means the operation is unauthenticated
+/// and callers need no bearer token, regardless of whether auth is
+/// configured for the server.
+pub fn is_secured(spec: &OpenAPI, operation: &Operation) -> bool {
+    !effective_requirements(spec, operation).is_empty()
+}
+
+/// The OAuth2 scopes that satisfy an operation's `security`: a list of
+/// alternatives combined with OR, each alternative itself an AND of every
+/// scope named in that requirement object. A token is authorized if it
+/// satisfies any one alternative (see [`is_authorized`]). Derived from the
+/// operation's own `security` array, or the document's top-level default
+/// when the operation doesn't declare one of its own (per the `OpenAPI`
+/// spec).
+pub fn required_scopes(spec: &OpenAPI, operation: &Operation) -> Vec<Vec<String>> {
+    effective_requirements(spec, operation)
+        .iter()
+        .map(|requirement| scopes_for_requirement(spec, requirement))
+        .collect()
+}
+
+fn effective_requirements<'a>(spec: &'a OpenAPI, operation: &'a Operation) -> &'a [SecurityRequirement] {
+    operation
+        .security
+        .as_ref()
+        .or(spec.security.as_ref())
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn scopes_for_requirement(spec: &OpenAPI, requirement: &SecurityRequirement) -> Vec<String> {
+    let Some(components) = spec.components.as_ref() else {
+        return Vec::new();
+    };
+
+    requirement
+        .iter()
+        .filter(|(scheme_name, _)| {
+            matches!(
+                components.security_schemes.get(scheme_name.as_str()),
+                Some(ReferenceOr::Item(SecurityScheme::OAuth2 { .. }))
+            )
+        })
+        .flat_map(|(_, requested_scopes)| requested_scopes.clone())
+        .collect()
+}
+
+/// The subset of `required` scopes that `granted` does not contain, in the
+/// order `required` declares them. Empty means `granted` satisfies
+/// `required` on its own.
+pub fn missing_scopes(required: &[String], granted: &[String]) -> Vec<String> {
+    required
+        .iter()
+        .filter(|scope| !granted.contains(scope))
+        .cloned()
+        .collect()
+}
+
+/// Whether `granted` satisfies at least one of `alternatives` — the OR
+/// semantics of an OpenAPI `security` array. Each alternative is satisfied
+/// when it has no outstanding [`missing_scopes`], so an alternative with no
+/// scopes (`security: [{oauth: []}]`) is trivially satisfied by any token.
+/// An empty `alternatives` list (operation unsecured) is never satisfied;
+/// callers should gate on [`is_secured`] before requiring a token at all.
+pub fn is_authorized(alternatives: &[Vec<String>], granted: &[String]) -> bool {
+    alternatives
+        .iter()
+        .any(|required| missing_scopes(required, granted).is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openapiv3::{Components, Info, OAuth2Flows, Paths};
+
+    fn spec_with_oauth_scheme(top_level_security: Vec<SecurityRequirement>) -> OpenAPI {
+        let mut components = Components::default();
+        components.security_schemes.insert(
+            "oauth".to_string(),
+            ReferenceOr::Item(SecurityScheme::OAuth2 {
+                flows: OAuth2Flows::default(),
+                description: None,
+                extensions: Default::default(),
+            }),
+        );
+
+        OpenAPI {
+            openapi: "3.0.0".to_string(),
+            info: Info {
+                title: "Test".to_string(),
+                version: "1.0.0".to_string(),
+                ..Default::default()
+            },
+            paths: Paths::default(),
+            components: Some(components),
+            security: Some(top_level_security),
+            ..Default::default()
+        }
+    }
+
+    fn requirement(scopes: &[&str]) -> SecurityRequirement {
+        let mut req = SecurityRequirement::new();
+        req.insert(
+            "oauth".to_string(),
+            scopes.iter().map(|s| s.to_string()).collect(),
+        );
+        req
+    }
+
+    #[test]
+    fn test_operation_security_overrides_default() {
+        let spec = spec_with_oauth_scheme(vec![requirement(&["default:read"])]);
+        let operation = Operation {
+            security: Some(vec![requirement(&["pets:write"])]),
+            ..Default::default()
+        };
+        assert_eq!(
+            required_scopes(&spec, &operation),
+            vec![vec!["pets:write".to_string()]]
+        );
+    }
+
+    #[test]
+    fn test_operation_falls_back_to_top_level_default() {
+        let spec = spec_with_oauth_scheme(vec![requirement(&["default:read"])]);
+        let operation = Operation::default();
+        assert_eq!(
+            required_scopes(&spec, &operation),
+            vec![vec!["default:read".to_string()]]
+        );
+    }
+
+    #[test]
+    fn test_required_scopes_keeps_alternatives_separate() {
+        let spec = spec_with_oauth_scheme(vec![]);
+        let operation = Operation {
+            security: Some(vec![requirement(&["read"]), requirement(&["write"])]),
+            ..Default::default()
+        };
+        assert_eq!(
+            required_scopes(&spec, &operation),
+            vec![vec!["read".to_string()], vec!["write".to_string()]]
+        );
+    }
+
+    #[test]
+    fn test_is_secured() {
+        let spec = spec_with_oauth_scheme(vec![requirement(&["default:read"])]);
+        assert!(is_secured(&spec, &Operation::default()));
+
+        let unsecured_spec = spec_with_oauth_scheme(vec![]);
+        assert!(!is_secured(&unsecured_spec, &Operation::default()));
+    }
+
+    #[test]
+    fn test_missing_scopes() {
+        let required = vec!["a".to_string(), "b".to_string()];
+        let granted = vec!["a".to_string()];
+        assert_eq!(missing_scopes(&required, &granted), vec!["b".to_string()]);
+        assert!(missing_scopes(&required, &["a".to_string(), "b".to_string()]).is_empty());
+    }
+
+    #[test]
+    fn test_is_authorized_by_read_or_write() {
+        let alternatives = vec![vec!["read".to_string()], vec!["write".to_string()]];
+        assert!(is_authorized(&alternatives, &["read".to_string()]));
+        assert!(is_authorized(&alternatives, &["write".to_string()]));
+        assert!(!is_authorized(&alternatives, &["other".to_string()]));
+    }
+
+    #[test]
+    fn test_is_authorized_trivially_satisfies_scopeless_requirement() {
+        let alternatives = vec![Vec::new()];
+        assert!(is_authorized(&alternatives, &[]));
+    }
+
+    #[test]
+    fn test_is_authorized_rejects_unsecured_empty_alternatives() {
+        assert!(!is_authorized(&[], &["anything".to_string()]));
+    }
+}
@@ -18,6 +18,11 @@ pub enum Command {
         /// Output directory
         #[arg(short, long, default_value = "./output")]
         output: PathBuf,
+        /// Default identifier casing for generated Luau fields/params, relative
+        /// to their OpenAPI wire names: preserve, camelCase, or snake_case.
+        /// A spec may override this default via a top-level `x-identifier-casing` key.
+        #[arg(long, default_value = "preserve")]
+        naming: String,
     },
     /// Start MCP server from a generated directory
     Serve {
@@ -39,6 +44,9 @@ pub enum Command {
         /// Explicit JWKS URI (optional, derived from authority via OIDC discovery if not set)
         #[arg(long, env = "MCP_AUTH_JWKS_URI")]
         auth_jwks_uri: Option<String>,
+        /// Comma-separated JWT signing algorithms to accept (default: all supported)
+        #[arg(long, value_delimiter = ',', env = "MCP_AUTH_ALGORITHMS")]
+        auth_algorithms: Vec<String>,
         /// Script execution timeout in seconds
         #[arg(long, default_value = "30")]
         timeout: u64,
@@ -48,12 +56,20 @@ pub enum Command {
         /// Maximum API calls per script execution
         #[arg(long, default_value = "100")]
         max_api_calls: usize,
+        /// How to treat tool-call arguments with properties a tool's input schema doesn't declare
+        #[arg(long, default_value = "reject")]
+        unknown_properties: String,
     },
     /// Generate and serve in one step
     Run {
         /// `OpenAPI` spec sources (file paths or URLs)
         #[arg(required = true)]
         specs: Vec<String>,
+        /// Default identifier casing for generated Luau fields/params, relative
+        /// to their OpenAPI wire names: preserve, camelCase, or snake_case.
+        /// A spec may override this default via a top-level `x-identifier-casing` key.
+        #[arg(long, default_value = "preserve")]
+        naming: String,
         /// Transport type
         #[arg(long, default_value = "stdio")]
         transport: String,
@@ -69,6 +85,9 @@ pub enum Command {
         /// Explicit JWKS URI (optional, derived from authority via OIDC discovery if not set)
         #[arg(long, env = "MCP_AUTH_JWKS_URI")]
         auth_jwks_uri: Option<String>,
+        /// Comma-separated JWT signing algorithms to accept (default: all supported)
+        #[arg(long, value_delimiter = ',', env = "MCP_AUTH_ALGORITHMS")]
+        auth_algorithms: Vec<String>,
         /// Script execution timeout in seconds
         #[arg(long, default_value = "30")]
         timeout: u64,
@@ -78,6 +97,28 @@ pub enum Command {
         /// Maximum API calls per script execution
         #[arg(long, default_value = "100")]
         max_api_calls: usize,
+        /// How to treat tool-call arguments with properties a tool's input schema doesn't declare
+        #[arg(long, default_value = "reject")]
+        unknown_properties: String,
+    },
+    /// Report server name/version, registered tools, supported transports,
+    /// and sandbox limits for a generated output directory
+    Describe {
+        /// Path to generated output directory
+        #[arg(required = true)]
+        dir: PathBuf,
+        /// Script execution timeout in seconds
+        #[arg(long, default_value = "30")]
+        timeout: u64,
+        /// Luau VM memory limit in megabytes
+        #[arg(long, default_value = "64")]
+        memory_limit: usize,
+        /// Maximum API calls per script execution
+        #[arg(long, default_value = "100")]
+        max_api_calls: usize,
+        /// How to treat tool-call arguments with properties a tool's input schema doesn't declare
+        #[arg(long, default_value = "reject")]
+        unknown_properties: String,
     },
 }
 
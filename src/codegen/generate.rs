@@ -0,0 +1,337 @@
+//! Top-level `generate` entry point: turns a set of `OpenAPI` spec sources
+//! into a directory of generated MCP tool definitions.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use openapiv3::{
+    OpenAPI, Operation, Parameter, ParameterData, ParameterSchemaOrContent, PathItem, ReferenceOr, RequestBody, Schema,
+};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::auth::scopes;
+use crate::codegen::luau_types::json_schema_to_params;
+
+use super::manifest::{McpParamDef, NamingPolicy};
+use super::parser::{load_spec_from_file, load_spec_from_url, resolve_naming_policy};
+
+/// Generate an MCP server directory from the given spec sources.
+///
+/// Each entry in `specs` is a local file path or an `http(s)://` URL; the resulting manifest and
+/// Luau tool stubs are written under `output`. `default_naming` is the
+/// identifier-casing policy applied to fields/params, unless a spec
+/// overrides it via its own `x-identifier-casing` extension (see
+/// [`resolve_naming_policy`]).
+pub async fn generate(specs: &[String], output: &Path, default_naming: NamingPolicy) -> Result<()> {
+    std::fs::create_dir_all(output)?;
+    let scripts_dir = output.join("scripts");
+    std::fs::create_dir_all(&scripts_dir)?;
+
+    let mut tools = Vec::new();
+    let mut used_names = HashSet::new();
+
+    for spec_source in specs {
+        let spec = if spec_source.starts_with("http://") || spec_source.starts_with("https://") {
+            load_spec_from_url(spec_source).await?
+        } else {
+            load_spec_from_file(Path::new(spec_source)).await?
+        };
+        let naming = resolve_naming_policy(&spec, default_naming);
+
+        for (path, path_item_ref) in &spec.paths.paths {
+            let ReferenceOr::Item(path_item) = path_item_ref else {
+                continue;
+            };
+
+            for (method, operation) in operations(path_item) {
+                let tool = build_tool(
+                    &spec,
+                    path,
+                    method,
+                    path_item,
+                    operation,
+                    naming,
+                    &mut used_names,
+                    &scripts_dir,
+                )?;
+                tools.push(tool);
+            }
+        }
+    }
+
+    let manifest_path = output.join("manifest.json");
+    let manifest_json =
+        serde_json::to_string_pretty(&Manifest { tools }).context("Failed to serialize generated manifest")?;
+    std::fs::write(&manifest_path, manifest_json)
+        .with_context(|| format!("Failed to write {}", manifest_path.display()))?;
+
+    Ok(())
+}
+
+/// The serialized shape of `manifest.json`, mirroring
+/// [`crate::serve::tool::load_tools`]'s expectations.
+#[derive(Serialize)]
+struct Manifest {
+    tools: Vec<ManifestToolOut>,
+}
+
+#[derive(Serialize)]
+struct ManifestToolOut {
+    name: String,
+    description: Option<String>,
+    params: Vec<ManifestParamOut>,
+    script: String,
+    auth_required: bool,
+    required_scopes: Vec<Vec<String>>,
+    input_schema: Value,
+}
+
+#[derive(Serialize)]
+struct ManifestParamOut {
+    wire_name: String,
+    name: String,
+    luau_type: String,
+    required: bool,
+    description: Option<String>,
+}
+
+impl From<McpParamDef> for ManifestParamOut {
+    fn from(param: McpParamDef) -> Self {
+        Self {
+            wire_name: param.wire_name,
+            name: param.name,
+            luau_type: param.luau_type,
+            required: param.required,
+            description: param.description,
+        }
+    }
+}
+
+/// Every declared HTTP-method operation on `path_item`, paired with its
+/// lowercase method name.
+fn operations(path_item: &PathItem) -> Vec<(&'static str, &Operation)> {
+    [
+        ("get", &path_item.get),
+        ("put", &path_item.put),
+        ("post", &path_item.post),
+        ("delete", &path_item.delete),
+        ("options", &path_item.options),
+        ("head", &path_item.head),
+        ("patch", &path_item.patch),
+        ("trace", &path_item.trace),
+    ]
+    .into_iter()
+    .filter_map(|(method, operation)| operation.as_ref().map(|operation| (method, operation)))
+    .collect()
+}
+
+/// Build one [`ManifestToolOut`] for a single `OpenAPI` operation, writing
+/// its Luau script to `scripts_dir` as a side effect.
+fn build_tool(
+    spec: &OpenAPI,
+    path: &str,
+    method: &str,
+    path_item: &PathItem,
+    operation: &Operation,
+    naming: NamingPolicy,
+    used_names: &mut HashSet<String>,
+    scripts_dir: &Path,
+) -> Result<ManifestToolOut> {
+    let name = unique_tool_name(
+        operation
+            .operation_id
+            .clone()
+            .unwrap_or_else(|| default_tool_name(method, path)),
+        used_names,
+    );
+
+    let parameters: Vec<&ReferenceOr<Parameter>> = path_item.parameters.iter().chain(&operation.parameters).collect();
+    let input_schema = operation_input_schema(spec, &parameters, operation.request_body.as_ref());
+    let params = json_schema_to_params(&input_schema, naming);
+
+    let script_file = format!("{name}.lua");
+    std::fs::write(scripts_dir.join(&script_file), tool_script(method, path))
+        .with_context(|| format!("Failed to write Luau script for tool '{name}'"))?;
+
+    Ok(ManifestToolOut {
+        description: operation.description.clone().or_else(|| operation.summary.clone()),
+        params: params.into_iter().map(ManifestParamOut::from).collect(),
+        script: format!("scripts/{script_file}"),
+        auth_required: scopes::is_secured(spec, operation),
+        required_scopes: scopes::required_scopes(spec, operation),
+        input_schema,
+        name,
+    })
+}
+
+/// A tool name derived from an operation lacking an `operationId`, e.g.
+/// `GET /pets/{petId}` becomes `get_pets_petId`.
+fn default_tool_name(method: &str, path: &str) -> String {
+    let mut name = method.to_string();
+    for segment in path.split('/') {
+        let segment = segment.trim_matches(|c| c == '{' || c == '}');
+        if segment.is_empty() {
+            continue;
+        }
+        name.push('_');
+        name.push_str(segment);
+    }
+    name
+}
+
+/// Disambiguate a tool name against every name already used in this
+/// `generate` run, the same way [`super::refs::resolve_external_refs`]
+/// disambiguates inlined schema names.
+fn unique_tool_name(base: String, used: &mut HashSet<String>) -> String {
+    if used.insert(base.clone()) {
+        return base;
+    }
+
+    let mut suffix = 2;
+    loop {
+        let candidate = format!("{base}_{suffix}");
+        if used.insert(candidate.clone()) {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
+
+/// Build the flat JSON Schema object tool-call arguments are validated
+/// against: the operation's path/query/header parameters as top-level
+/// properties, plus the `application/json` request body's own properties
+/// merged in (or, for a non-object body, a single `body` property holding
+/// it whole).
+fn operation_input_schema(
+    spec: &OpenAPI,
+    parameters: &[&ReferenceOr<Parameter>],
+    request_body: Option<&ReferenceOr<RequestBody>>,
+) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+
+    for param_ref in parameters {
+        let Some(parameter) = resolve_parameter(spec, param_ref) else {
+            continue;
+        };
+        let data = parameter_data(parameter);
+        let Some(schema) = parameter_schema(data) else {
+            continue;
+        };
+
+        if data.required {
+            required.push(Value::String(data.name.clone()));
+        }
+        properties.insert(data.name.clone(), schema);
+    }
+
+    if let Some(body) = request_body.and_then(|body_ref| resolve_request_body(spec, body_ref)) {
+        if let Some(schema_ref) = body.content.get("application/json").and_then(|media| media.schema.as_ref()) {
+            merge_body_schema(&schema_to_value(schema_ref), body.required, &mut properties, &mut required);
+        }
+    }
+
+    serde_json::json!({
+        "type": "object",
+        "properties": Value::Object(properties),
+        "required": required,
+    })
+}
+
+/// Merge a request body's schema into the flat top-level `properties`: its
+/// own properties directly, if it's an object schema, otherwise a single
+/// `body` property holding the whole schema.
+fn merge_body_schema(schema: &Value, body_required: bool, properties: &mut Map<String, Value>, required: &mut Vec<Value>) {
+    let Some(body_properties) = schema.get("properties").and_then(Value::as_object) else {
+        properties.insert("body".to_string(), schema.clone());
+        if body_required {
+            required.push(Value::String("body".to_string()));
+        }
+        return;
+    };
+
+    let body_required_set: HashSet<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|values| values.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    for (name, prop) in body_properties {
+        properties.insert(name.clone(), prop.clone());
+        if body_required_set.contains(name.as_str()) {
+            required.push(Value::String(name.clone()));
+        }
+    }
+}
+
+fn parameter_data(parameter: &Parameter) -> &ParameterData {
+    match parameter {
+        Parameter::Query { parameter_data, .. }
+        | Parameter::Header { parameter_data, .. }
+        | Parameter::Path { parameter_data, .. }
+        | Parameter::Cookie { parameter_data, .. } => parameter_data,
+    }
+}
+
+fn parameter_schema(data: &ParameterData) -> Option<Value> {
+    match &data.format {
+        ParameterSchemaOrContent::Schema(schema_ref) => Some(schema_to_value(schema_ref)),
+        ParameterSchemaOrContent::Content(content) => {
+            content.values().next().and_then(|media| media.schema.as_ref()).map(schema_to_value)
+        }
+    }
+}
+
+/// Render a (possibly `$ref`-erenced) `OpenAPI` schema as the JSON Schema
+/// `Value` shape [`json_schema_to_params`]/[`super::luau_types::json_schema_prop_to_field_type`]
+/// expect.
+fn schema_to_value(schema_ref: &ReferenceOr<Schema>) -> Value {
+    match schema_ref {
+        ReferenceOr::Reference { reference } => serde_json::json!({ "$ref": reference }),
+        ReferenceOr::Item(schema) => serde_json::to_value(schema).unwrap_or(Value::Null),
+    }
+}
+
+fn resolve_parameter<'a>(spec: &'a OpenAPI, param_ref: &'a ReferenceOr<Parameter>) -> Option<&'a Parameter> {
+    match param_ref {
+        ReferenceOr::Item(parameter) => Some(parameter),
+        ReferenceOr::Reference { reference } => {
+            let name = reference.rsplit('/').next()?;
+            match spec.components.as_ref()?.parameters.get(name)? {
+                ReferenceOr::Item(parameter) => Some(parameter),
+                ReferenceOr::Reference { .. } => None,
+            }
+        }
+    }
+}
+
+fn resolve_request_body<'a>(spec: &'a OpenAPI, body_ref: &'a ReferenceOr<RequestBody>) -> Option<&'a RequestBody> {
+    match body_ref {
+        ReferenceOr::Item(body) => Some(body),
+        ReferenceOr::Reference { reference } => {
+            let name = reference.rsplit('/').next()?;
+            match spec.components.as_ref()?.request_bodies.get(name)? {
+                ReferenceOr::Item(body) => Some(body),
+                ReferenceOr::Reference { .. } => None,
+            }
+        }
+    }
+}
+
+/// A Luau script stub for a generated tool. The codegen pipeline does not
+/// yet emit an actual upstream HTTP request for the operation; the stub
+/// consumes one unit of API-call budget and echoes its arguments back, so
+/// generated servers are runnable end-to-end while that dispatch is built.
+fn tool_script(method: &str, path: &str) -> String {
+    format!(
+        "-- {} {}\n\
+         return function(input)\n\
+         \tapi.call()\n\
+         \treturn input\n\
+         end\n",
+        method.to_uppercase(),
+        path
+    )
+}
@@ -4,7 +4,7 @@
 
 use serde_json::Value;
 
-use super::manifest::{FieldDef, FieldType, McpParamDef, SchemaDef};
+use super::manifest::{FieldDef, FieldType, McpParamDef, NamingPolicy, SchemaDef};
 
 /// Convert a JSON Schema type string to the corresponding Luau type name.
 ///
@@ -28,7 +28,12 @@ pub fn json_schema_type_to_luau(type_str: &str, items: Option<&Value>) -> String
 
 /// Convert a JSON Schema object (with `properties` / `required`) into a list of
 /// [`McpParamDef`] entries suitable for MCP tool parameter metadata.
-pub fn json_schema_to_params(schema: &Value) -> Vec<McpParamDef> {
+///
+/// `policy` controls how each wire property name is transformed into the
+/// Luau identifier carried in [`McpParamDef::name`]; the original wire name
+/// is preserved in [`McpParamDef::wire_name`] for re-serializing call
+/// arguments back to the upstream API (see [`rename_params_to_wire`]).
+pub fn json_schema_to_params(schema: &Value, policy: NamingPolicy) -> Vec<McpParamDef> {
     let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
         return Vec::new();
     };
@@ -41,34 +46,111 @@ pub fn json_schema_to_params(schema: &Value) -> Vec<McpParamDef> {
 
     let mut params: Vec<McpParamDef> = properties
         .iter()
-        .map(|(name, prop)| {
-            let type_str = prop.get("type").and_then(Value::as_str).unwrap_or("any");
-            let items = prop.get("items");
-            let luau_type = json_schema_type_to_luau(type_str, items);
+        .map(|(wire_name, prop)| {
+            let luau_type = json_schema_prop_to_field_type(prop, policy).to_luau_type();
             let description = prop
                 .get("description")
                 .and_then(Value::as_str)
                 .map(String::from);
 
             McpParamDef {
-                name: name.clone(),
+                wire_name: wire_name.clone(),
+                name: policy.apply(wire_name),
                 luau_type,
-                required: required_set.contains(name.as_str()),
+                required: required_set.contains(wire_name.as_str()),
                 description,
             }
         })
         .collect();
 
-    // Sort for deterministic output.
-    params.sort_by(|a, b| a.name.cmp(&b.name));
+    // Sort on the wire name (not the policy-transformed identifier) so
+    // output stays stable across naming policies.
+    params.sort_by(|a, b| a.wire_name.cmp(&b.wire_name));
+    disambiguate_names(&mut params, |p| &p.name, |p, name| p.name = name);
     params
 }
 
+/// Deterministically rename colliding `name`s in `items` (sorted by wire
+/// name beforehand, so the outcome doesn't depend on map iteration order)
+/// by appending `_2`, `_3`, etc. to every name after the first occurrence.
+///
+/// Two distinct wire names can transform to the same identifier under a
+/// [`NamingPolicy`] (e.g. `pet_id` and `petId` both become `petId` under
+/// `CamelCase`); left alone, that collision would silently drop one field's
+/// value when re-keying between Luau identifiers and wire names (see
+/// [`rename_params_to_wire`]).
+fn disambiguate_names<T>(items: &mut [T], name: impl Fn(&T) -> &str, set_name: impl Fn(&mut T, String)) {
+    let mut seen: std::collections::HashSet<String> = std::collections::HashSet::new();
+    for item in items.iter_mut() {
+        let current = name(item).to_string();
+        if seen.insert(current.clone()) {
+            continue;
+        }
+
+        let mut suffix = 2;
+        loop {
+            let candidate = format!("{current}_{suffix}");
+            if seen.insert(candidate.clone()) {
+                set_name(item, candidate);
+                break;
+            }
+            suffix += 1;
+        }
+    }
+}
+
+/// Re-key a tool-call arguments object from its original wire names to the
+/// Luau identifiers a generated tool script is authored against.
+///
+/// Keys with no matching param (e.g. unknown properties admitted by a
+/// permissive `additionalProperties`) pass through unchanged.
+pub fn rename_params_to_luau(value: &Value, params: &[McpParamDef]) -> Value {
+    let Some(map) = value.as_object() else {
+        return value.clone();
+    };
+
+    let mut luau_map = serde_json::Map::with_capacity(map.len());
+    for (wire_name, val) in map {
+        let name = params
+            .iter()
+            .find(|p| p.wire_name == *wire_name)
+            .map_or_else(|| wire_name.clone(), |p| p.name.clone());
+        luau_map.insert(name, val.clone());
+    }
+    Value::Object(luau_map)
+}
+
+/// Re-key a tool-call arguments object from its Luau identifiers back to the
+/// original wire names, so it can be forwarded to the upstream API.
+///
+/// This is the inverse of [`rename_params_to_luau`]: a Luau script authored
+/// against `params[i].name` produces a value keyed the same way, and this
+/// function restores `params[i].wire_name` before the value is serialized
+/// into the outgoing request. Keys with no matching param (e.g.
+/// already-unknown properties) pass through unchanged.
+pub fn rename_params_to_wire(value: &Value, params: &[McpParamDef]) -> Value {
+    let Some(map) = value.as_object() else {
+        return value.clone();
+    };
+
+    let mut wire_map = serde_json::Map::with_capacity(map.len());
+    for (name, val) in map {
+        let wire_name = params
+            .iter()
+            .find(|p| p.name == *name)
+            .map_or_else(|| name.clone(), |p| p.wire_name.clone());
+        wire_map.insert(wire_name, val.clone());
+    }
+    Value::Object(wire_map)
+}
+
 /// Convert a single JSON Schema property value into a [`FieldType`].
 ///
-/// Handles `$ref`, primitive types, arrays, and objects (with or without
-/// explicit `properties`).
-pub fn json_schema_prop_to_field_type(prop: &Value) -> FieldType {
+/// Handles `$ref`, primitive types, arrays, objects (with or without
+/// explicit `properties`), `enum`, `nullable`/`type: [..., "null"]`, and the
+/// composite keywords `allOf`/`oneOf`/`anyOf`. `policy` is applied to any
+/// nested object field names encountered along the way.
+pub fn json_schema_prop_to_field_type(prop: &Value, policy: NamingPolicy) -> FieldType {
     // Handle $ref
     if let Some(ref_str) = prop.get("$ref").and_then(Value::as_str) {
         let schema_name = ref_str.rsplit('/').next().unwrap_or(ref_str).to_string();
@@ -77,30 +159,147 @@ pub fn json_schema_prop_to_field_type(prop: &Value) -> FieldType {
         };
     }
 
-    let type_str = prop.get("type").and_then(Value::as_str).unwrap_or("");
+    let nullable = prop_is_nullable(prop);
 
-    match type_str {
+    if let Some(subschemas) = prop.get("allOf").and_then(Value::as_array) {
+        return wrap_nullable(merge_all_of(subschemas, policy), nullable);
+    }
+
+    if let Some(subschemas) = prop
+        .get("oneOf")
+        .or_else(|| prop.get("anyOf"))
+        .and_then(Value::as_array)
+    {
+        return wrap_nullable(union_of(subschemas, policy), nullable);
+    }
+
+    if let Some(values) = prop_enum_values(prop) {
+        return wrap_nullable(FieldType::Literal(values), nullable);
+    }
+
+    let type_str = prop_type_str(prop);
+
+    let base = match type_str {
         "integer" => FieldType::Integer,
         "number" => FieldType::Number,
         "boolean" => FieldType::Boolean,
         "array" => {
             let items_type = prop
                 .get("items")
-                .map_or(FieldType::String, json_schema_prop_to_field_type);
+                .map_or(FieldType::String, |items| json_schema_prop_to_field_type(items, policy));
             FieldType::Array {
                 items: Box::new(items_type),
             }
         }
-        "object" => object_field_type(prop),
+        "object" => object_field_type(prop, policy),
         // "string" and unknown types both fall back to String.
         _ => FieldType::String,
+    };
+
+    wrap_nullable(base, nullable)
+}
+
+/// Extract the `enum` values of a property as strings, if any are present.
+fn prop_enum_values(prop: &Value) -> Option<Vec<String>> {
+    let values: Vec<String> = prop
+        .get("enum")
+        .and_then(Value::as_array)?
+        .iter()
+        .filter_map(Value::as_str)
+        .map(String::from)
+        .collect();
+    (!values.is_empty()).then_some(values)
+}
+
+/// Read a property's `"type"`, accepting both the plain string form and the
+/// JSON-Schema `type: ["string", "null"]` array form (ignoring `"null"`).
+fn prop_type_str(prop: &Value) -> &str {
+    match prop.get("type") {
+        Some(Value::String(s)) => s,
+        Some(Value::Array(types)) => types
+            .iter()
+            .find_map(|t| t.as_str().filter(|s| *s != "null"))
+            .unwrap_or(""),
+        _ => "",
+    }
+}
+
+/// Whether a property is nullable, via `nullable: true` or a `"null"` member
+/// of a `type` array.
+fn prop_is_nullable(prop: &Value) -> bool {
+    if prop.get("nullable").and_then(Value::as_bool) == Some(true) {
+        return true;
+    }
+    matches!(
+        prop.get("type"),
+        Some(Value::Array(types)) if types.iter().any(|t| t.as_str() == Some("null"))
+    )
+}
+
+/// Wrap `field_type` in a [`FieldType::Union`] with the nil sentinel when
+/// `nullable` is set, so it renders as Luau's `T?`.
+fn wrap_nullable(field_type: FieldType, nullable: bool) -> FieldType {
+    if nullable {
+        FieldType::Union(vec![field_type, FieldType::nil_sentinel()])
+    } else {
+        field_type
+    }
+}
+
+/// Convert `oneOf`/`anyOf` subschemas into a [`FieldType::Union`]. An empty
+/// list falls back to `any`, rendered via an empty union.
+fn union_of(subschemas: &[Value], policy: NamingPolicy) -> FieldType {
+    FieldType::Union(
+        subschemas
+            .iter()
+            .map(|s| json_schema_prop_to_field_type(s, policy))
+            .collect(),
+    )
+}
+
+/// Merge `allOf` subschemas. When every member resolves to an
+/// [`FieldType::InlineObject`], their `properties` are merged into one
+/// object with the union of all `required` flags; otherwise a single member
+/// is returned as-is and several members are combined into a
+/// [`FieldType::Union`]. An empty list falls back to `any`.
+fn merge_all_of(subschemas: &[Value], policy: NamingPolicy) -> FieldType {
+    if subschemas.is_empty() {
+        return FieldType::Union(Vec::new());
+    }
+
+    let resolved: Vec<FieldType> = subschemas
+        .iter()
+        .map(|s| json_schema_prop_to_field_type(s, policy))
+        .collect();
+
+    if resolved.iter().all(|f| matches!(f, FieldType::InlineObject { .. })) {
+        let mut by_name: std::collections::BTreeMap<String, FieldDef> =
+            std::collections::BTreeMap::new();
+        for field_type in resolved {
+            let FieldType::InlineObject { fields } = field_type else {
+                unreachable!("filtered to InlineObject above")
+            };
+            for field in fields {
+                by_name
+                    .entry(field.wire_name.clone())
+                    .and_modify(|existing| existing.required = existing.required || field.required)
+                    .or_insert(field);
+            }
+        }
+        FieldType::InlineObject {
+            fields: by_name.into_values().collect(),
+        }
+    } else if let [only] = resolved.as_slice() {
+        only.clone()
+    } else {
+        FieldType::Union(resolved)
     }
 }
 
 /// Build a [`FieldType`] for a JSON Schema `"object"` type, distinguishing
 /// between objects with explicit `properties` ([`FieldType::InlineObject`]) and
 /// bare objects ([`FieldType::Map`]).
-fn object_field_type(prop: &Value) -> FieldType {
+fn object_field_type(prop: &Value, policy: NamingPolicy) -> FieldType {
     let Some(properties) = prop.get("properties").and_then(Value::as_object) else {
         return FieldType::Map {
             value: Box::new(FieldType::String),
@@ -115,27 +314,30 @@ fn object_field_type(prop: &Value) -> FieldType {
 
     let mut fields: Vec<FieldDef> = properties
         .iter()
-        .map(|(name, fprop)| FieldDef {
-            name: name.clone(),
-            field_type: json_schema_prop_to_field_type(fprop),
-            required: required_set.contains(name.as_str()),
+        .map(|(wire_name, fprop)| FieldDef {
+            wire_name: wire_name.clone(),
+            name: policy.apply(wire_name),
+            field_type: json_schema_prop_to_field_type(fprop, policy),
+            required: required_set.contains(wire_name.as_str()),
             description: fprop
                 .get("description")
                 .and_then(Value::as_str)
                 .map(String::from),
-            enum_values: None,
-            nullable: false,
+            enum_values: prop_enum_values(fprop),
+            nullable: prop_is_nullable(fprop),
             format: None,
         })
         .collect();
 
-    fields.sort_by(|a, b| a.name.cmp(&b.name));
+    fields.sort_by(|a, b| a.wire_name.cmp(&b.wire_name));
+    disambiguate_names(&mut fields, |f| &f.name, |f, name| f.name = name);
     FieldType::InlineObject { fields }
 }
 
 /// Extract named schema definitions from `$defs` or `definitions` in a JSON
-/// Schema document, converting each into a [`SchemaDef`].
-pub fn extract_schema_defs(schema: &Value) -> Vec<SchemaDef> {
+/// Schema document, converting each into a [`SchemaDef`]. `policy` controls
+/// how each field's wire name is transformed into its Luau identifier.
+pub fn extract_schema_defs(schema: &Value, policy: NamingPolicy) -> Vec<SchemaDef> {
     let defs_obj = schema
         .get("$defs")
         .or_else(|| schema.get("definitions"))
@@ -160,23 +362,25 @@ pub fn extract_schema_defs(schema: &Value) -> Vec<SchemaDef> {
                 .map(|props| {
                     props
                         .iter()
-                        .map(|(fname, fprop)| FieldDef {
-                            name: fname.clone(),
-                            field_type: json_schema_prop_to_field_type(fprop),
-                            required: required_set.contains(fname.as_str()),
+                        .map(|(wire_name, fprop)| FieldDef {
+                            wire_name: wire_name.clone(),
+                            name: policy.apply(wire_name),
+                            field_type: json_schema_prop_to_field_type(fprop, policy),
+                            required: required_set.contains(wire_name.as_str()),
                             description: fprop
                                 .get("description")
                                 .and_then(Value::as_str)
                                 .map(String::from),
-                            enum_values: None,
-                            nullable: false,
+                            enum_values: prop_enum_values(fprop),
+                            nullable: prop_is_nullable(fprop),
                             format: None,
                         })
                         .collect()
                 })
                 .unwrap_or_default();
 
-            fields.sort_by(|a, b| a.name.cmp(&b.name));
+            fields.sort_by(|a, b| a.wire_name.cmp(&b.wire_name));
+            disambiguate_names(&mut fields, |f| &f.name, |f, name| f.name = name);
 
             SchemaDef {
                 name: name.clone(),
@@ -217,7 +421,7 @@ mod tests {
                 "encoding": { "type": "string" }
             }
         });
-        let params = json_schema_to_params(&schema);
+        let params = json_schema_to_params(&schema, NamingPolicy::Preserve);
         assert_eq!(params.len(), 2);
         let path_param = params.iter().find(|p| p.name == "path").unwrap();
         assert!(path_param.required);
@@ -245,7 +449,7 @@ mod tests {
                 }
             }
         });
-        let defs = extract_schema_defs(&schema);
+        let defs = extract_schema_defs(&schema, NamingPolicy::Preserve);
         assert_eq!(defs.len(), 1);
         assert_eq!(defs[0].name, "User");
         assert_eq!(defs[0].fields.len(), 2);
@@ -254,11 +458,11 @@ mod tests {
     #[test]
     fn test_json_schema_to_field_type() {
         let prop = serde_json::json!({ "type": "string" });
-        assert_eq!(json_schema_prop_to_field_type(&prop), FieldType::String);
+        assert_eq!(json_schema_prop_to_field_type(&prop, NamingPolicy::Preserve), FieldType::String);
 
         let arr = serde_json::json!({ "type": "array", "items": { "type": "integer" } });
         assert_eq!(
-            json_schema_prop_to_field_type(&arr),
+            json_schema_prop_to_field_type(&arr, NamingPolicy::Preserve),
             FieldType::Array {
                 items: Box::new(FieldType::Integer)
             }
@@ -270,7 +474,7 @@ mod tests {
                 "x": { "type": "number" }
             }
         });
-        match json_schema_prop_to_field_type(&obj) {
+        match json_schema_prop_to_field_type(&obj, NamingPolicy::Preserve) {
             FieldType::InlineObject { fields } => {
                 assert_eq!(fields.len(), 1);
                 assert_eq!(fields[0].name, "x");
@@ -280,10 +484,213 @@ mod tests {
 
         let reftype = serde_json::json!({ "$ref": "#/$defs/User" });
         assert_eq!(
-            json_schema_prop_to_field_type(&reftype),
+            json_schema_prop_to_field_type(&reftype, NamingPolicy::Preserve),
             FieldType::Object {
                 schema: "User".to_string()
             }
         );
     }
+
+    #[test]
+    fn test_enum_becomes_literal() {
+        let prop = serde_json::json!({ "type": "string", "enum": ["a", "b"] });
+        assert_eq!(
+            json_schema_prop_to_field_type(&prop, NamingPolicy::Preserve),
+            FieldType::Literal(vec!["a".to_string(), "b".to_string()])
+        );
+        assert_eq!(
+            json_schema_prop_to_field_type(&prop, NamingPolicy::Preserve).to_luau_type(),
+            "\"a\" | \"b\""
+        );
+    }
+
+    #[test]
+    fn test_one_of_becomes_union() {
+        let prop = serde_json::json!({ "oneOf": [{ "type": "string" }, { "type": "integer" }] });
+        assert_eq!(
+            json_schema_prop_to_field_type(&prop, NamingPolicy::Preserve),
+            FieldType::Union(vec![FieldType::String, FieldType::Integer])
+        );
+        assert_eq!(
+            json_schema_prop_to_field_type(&prop, NamingPolicy::Preserve).to_luau_type(),
+            "string | number"
+        );
+    }
+
+    #[test]
+    fn test_any_of_empty_falls_back_to_any() {
+        let prop = serde_json::json!({ "anyOf": [] });
+        assert_eq!(
+            json_schema_prop_to_field_type(&prop, NamingPolicy::Preserve).to_luau_type(),
+            "any"
+        );
+    }
+
+    #[test]
+    fn test_nullable_renders_as_optional() {
+        let prop = serde_json::json!({ "type": "string", "nullable": true });
+        assert_eq!(
+            json_schema_prop_to_field_type(&prop, NamingPolicy::Preserve).to_luau_type(),
+            "string?"
+        );
+
+        let union_form = serde_json::json!({ "type": ["string", "null"] });
+        assert_eq!(
+            json_schema_prop_to_field_type(&union_form, NamingPolicy::Preserve).to_luau_type(),
+            "string?"
+        );
+    }
+
+    #[test]
+    fn test_all_of_merges_object_properties() {
+        let prop = serde_json::json!({
+            "allOf": [
+                {
+                    "type": "object",
+                    "required": ["name"],
+                    "properties": { "name": { "type": "string" } }
+                },
+                {
+                    "type": "object",
+                    "required": ["age"],
+                    "properties": { "age": { "type": "integer" } }
+                }
+            ]
+        });
+        match json_schema_prop_to_field_type(&prop, NamingPolicy::Preserve) {
+            FieldType::InlineObject { fields } => {
+                assert_eq!(fields.len(), 2);
+                assert_eq!(fields[0].name, "age");
+                assert!(fields[0].required);
+                assert_eq!(fields[1].name, "name");
+                assert!(fields[1].required);
+            }
+            other => panic!("Expected InlineObject, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_all_of_non_objects_becomes_union() {
+        let prop = serde_json::json!({
+            "allOf": [{ "type": "string" }, { "type": "integer" }]
+        });
+        assert_eq!(
+            json_schema_prop_to_field_type(&prop, NamingPolicy::Preserve),
+            FieldType::Union(vec![FieldType::String, FieldType::Integer])
+        );
+    }
+
+    #[test]
+    fn test_json_schema_to_params_camel_case_preserves_wire_name() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "required": ["pet_id"],
+            "properties": {
+                "pet_id": { "type": "string" }
+            }
+        });
+        let params = json_schema_to_params(&schema, NamingPolicy::CamelCase);
+        assert_eq!(params.len(), 1);
+        assert_eq!(params[0].wire_name, "pet_id");
+        assert_eq!(params[0].name, "petId");
+        assert!(params[0].required);
+    }
+
+    #[test]
+    fn test_json_schema_to_params_sorted_by_wire_name_under_camel_case() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "zebra_count": { "type": "integer" },
+                "apple_count": { "type": "integer" }
+            }
+        });
+        let params = json_schema_to_params(&schema, NamingPolicy::CamelCase);
+        let wire_names: Vec<&str> = params.iter().map(|p| p.wire_name.as_str()).collect();
+        assert_eq!(wire_names, vec!["apple_count", "zebra_count"]);
+    }
+
+    #[test]
+    fn test_json_schema_to_params_disambiguates_colliding_names() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "pet_id": { "type": "string" },
+                "petId": { "type": "string" }
+            }
+        });
+        let params = json_schema_to_params(&schema, NamingPolicy::CamelCase);
+        assert_eq!(params.len(), 2);
+        let names: std::collections::HashSet<&str> = params.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names.len(), 2, "colliding names must be disambiguated: {params:?}");
+        // Sorted by wire_name ("petId" < "pet_id"), so the first occurrence
+        // keeps the plain name and the later one gets the "_2" suffix.
+        assert_eq!(params[0].wire_name, "petId");
+        assert_eq!(params[0].name, "petId");
+        assert_eq!(params[1].wire_name, "pet_id");
+        assert_eq!(params[1].name, "petId_2");
+    }
+
+    #[test]
+    fn test_object_field_type_snake_case_renames_nested_fields() {
+        let prop = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "ownerName": { "type": "string" }
+            }
+        });
+        match json_schema_prop_to_field_type(&prop, NamingPolicy::SnakeCase) {
+            FieldType::InlineObject { fields } => {
+                assert_eq!(fields[0].wire_name, "ownerName");
+                assert_eq!(fields[0].name, "owner_name");
+            }
+            other => panic!("Expected InlineObject, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_rename_params_to_luau_applies_naming_policy() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "pet_id": { "type": "string" },
+                "owner_name": { "type": "string" }
+            }
+        });
+        let params = json_schema_to_params(&schema, NamingPolicy::CamelCase);
+
+        let wire_value = serde_json::json!({ "pet_id": "p1", "owner_name": "Ada" });
+        let luau_value = rename_params_to_luau(&wire_value, &params);
+
+        assert_eq!(
+            luau_value,
+            serde_json::json!({ "petId": "p1", "ownerName": "Ada" })
+        );
+    }
+
+    #[test]
+    fn test_rename_params_to_wire_is_inverse_of_naming_policy() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "pet_id": { "type": "string" },
+                "owner_name": { "type": "string" }
+            }
+        });
+        let params = json_schema_to_params(&schema, NamingPolicy::CamelCase);
+
+        let luau_value = serde_json::json!({ "petId": "p1", "ownerName": "Ada" });
+        let wire_value = rename_params_to_wire(&luau_value, &params);
+
+        assert_eq!(
+            wire_value,
+            serde_json::json!({ "pet_id": "p1", "owner_name": "Ada" })
+        );
+    }
+
+    #[test]
+    fn test_rename_params_to_wire_passes_through_unknown_keys() {
+        let wire_value = rename_params_to_wire(&serde_json::json!({ "extra": 1 }), &[]);
+        assert_eq!(wire_value, serde_json::json!({ "extra": 1 }));
+    }
 }
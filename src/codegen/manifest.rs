@@ -0,0 +1,263 @@
+//! Data model for the generated manifest: schema definitions, fields, and
+//! MCP tool parameter metadata shared across the codegen pipeline.
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error};
+
+/// How an `OpenAPI` wire name is transformed into the identifier used in
+/// emitted Luau type annotations and tool parameter metadata.
+///
+/// The wire name itself is always preserved alongside the transformed
+/// identifier (see [`FieldDef::wire_name`] / [`McpParamDef::wire_name`]) so
+/// a value built under the local convention can be re-serialized under the
+/// original wire key when calling the upstream API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NamingPolicy {
+    /// Use the wire name verbatim as the Luau identifier.
+    Preserve,
+    /// Transform the wire name to `camelCase`.
+    CamelCase,
+    /// Transform the wire name to `snake_case`.
+    SnakeCase,
+}
+
+impl NamingPolicy {
+    /// Apply this policy to a wire name, producing the Luau identifier.
+    pub fn apply(self, wire_name: &str) -> String {
+        match self {
+            NamingPolicy::Preserve => wire_name.to_string(),
+            NamingPolicy::CamelCase => camel_case(wire_name),
+            NamingPolicy::SnakeCase => snake_case(wire_name),
+        }
+    }
+}
+
+impl FromStr for NamingPolicy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(NamingPolicy::Preserve),
+            "camelCase" => Ok(NamingPolicy::CamelCase),
+            "snake_case" => Ok(NamingPolicy::SnakeCase),
+            other => Err(anyhow!(
+                "unknown naming policy '{other}', expected one of: preserve, camelCase, snake_case"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for NamingPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            NamingPolicy::Preserve => "preserve",
+            NamingPolicy::CamelCase => "camelCase",
+            NamingPolicy::SnakeCase => "snake_case",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Split an identifier into lowercase words on `_`/`-`/space boundaries and
+/// `lower -> Upper` case transitions (e.g. `"petId"` and `"pet_id"` both
+/// split into `["pet", "id"]`).
+fn split_words(s: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev_is_lower = false;
+
+    for c in s.chars() {
+        if c == '_' || c == '-' || c == ' ' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            prev_is_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_is_lower && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        current.push(c.to_ascii_lowercase());
+        prev_is_lower = c.is_lowercase();
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn camel_case(s: &str) -> String {
+    let mut out = String::new();
+    for (i, word) in split_words(s).iter().enumerate() {
+        if i == 0 {
+            out.push_str(word);
+        } else {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                out.extend(first.to_uppercase());
+                out.push_str(chars.as_str());
+            }
+        }
+    }
+    out
+}
+
+fn snake_case(s: &str) -> String {
+    split_words(s).join("_")
+}
+
+/// A named schema (e.g. an `OpenAPI` component or JSON Schema `$defs` entry)
+/// with its fields resolved to [`FieldType`]s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaDef {
+    pub name: String,
+    pub description: Option<String>,
+    pub fields: Vec<FieldDef>,
+}
+
+/// A single field on a [`SchemaDef`] or [`FieldType::InlineObject`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDef {
+    /// The original `OpenAPI`/JSON Schema property name, used for JSON
+    /// (de)serialization when calling the upstream API.
+    pub wire_name: String,
+    /// The Luau identifier this field is emitted as, derived from
+    /// `wire_name` by the active [`NamingPolicy`].
+    pub name: String,
+    pub field_type: FieldType,
+    pub required: bool,
+    pub description: Option<String>,
+    pub enum_values: Option<Vec<String>>,
+    pub nullable: bool,
+    pub format: Option<String>,
+}
+
+/// The Luau-facing type of a field, derived from a JSON Schema property.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldType {
+    String,
+    Integer,
+    Number,
+    Boolean,
+    /// Reference to a named schema, rendered as the schema's Luau type alias.
+    Object { schema: String },
+    Array { items: Box<FieldType> },
+    /// An object with known `properties`, rendered as an inline Luau table type.
+    InlineObject { fields: Vec<FieldDef> },
+    /// A bare object with no declared `properties`, rendered as `{[string]: V}`.
+    Map { value: Box<FieldType> },
+    /// `oneOf`/`anyOf`/nullable composition, rendered as `A | B | C`.
+    Union(Vec<FieldType>),
+    /// An `enum`, rendered as a union of string literals, e.g. `"a" | "b"`.
+    Literal(Vec<String>),
+}
+
+impl FieldType {
+    /// A marker used as one side of a [`FieldType::Union`] to represent
+    /// `nullable: true` / `type: [..., "null"]`, so it renders as Luau's `T?`
+    /// instead of a two-member union.
+    pub fn nil_sentinel() -> FieldType {
+        FieldType::Literal(Vec::new())
+    }
+
+    fn is_nil_sentinel(&self) -> bool {
+        matches!(self, FieldType::Literal(values) if values.is_empty())
+    }
+
+    /// Render this type as a Luau type annotation.
+    pub fn to_luau_type(&self) -> String {
+        match self {
+            FieldType::String => "string".to_string(),
+            FieldType::Integer | FieldType::Number => "number".to_string(),
+            FieldType::Boolean => "boolean".to_string(),
+            FieldType::Object { schema } => schema.clone(),
+            FieldType::Array { items } => format!("{{{}}}", items.to_luau_type()),
+            FieldType::InlineObject { fields } => {
+                let body = fields
+                    .iter()
+                    .map(|f| {
+                        let opt = if f.required { "" } else { "?" };
+                        format!("{}{}: {}", f.name, opt, f.field_type.to_luau_type())
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{{{body}}}")
+            }
+            FieldType::Map { value } => format!("{{[string]: {}}}", value.to_luau_type()),
+            FieldType::Union(variants) => match variants.as_slice() {
+                [] => "any".to_string(),
+                [a, b] if a.is_nil_sentinel() => format!("{}?", b.to_luau_type()),
+                [a, b] if b.is_nil_sentinel() => format!("{}?", a.to_luau_type()),
+                _ => variants
+                    .iter()
+                    .map(FieldType::to_luau_type)
+                    .collect::<Vec<_>>()
+                    .join(" | "),
+            },
+            FieldType::Literal(values) => values
+                .iter()
+                .map(|v| format!("\"{v}\""))
+                .collect::<Vec<_>>()
+                .join(" | "),
+        }
+    }
+}
+
+/// Parameter metadata for a single MCP tool input: its Luau type annotation
+/// and the mapping between its wire name and its Luau identifier.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct McpParamDef {
+    /// The original `OpenAPI`/JSON Schema property name, used for JSON
+    /// (de)serialization when calling the upstream API. Skipped when this
+    /// struct is serialized; MCP clients see wire names through the tool's
+    /// `inputSchema`.
+    #[serde(skip_serializing)]
+    pub wire_name: String,
+    pub name: String,
+    pub luau_type: String,
+    pub required: bool,
+    pub description: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_naming_policy_preserve() {
+        assert_eq!(NamingPolicy::Preserve.apply("pet_id"), "pet_id");
+        assert_eq!(NamingPolicy::Preserve.apply("petId"), "petId");
+    }
+
+    #[test]
+    fn test_naming_policy_camel_case() {
+        assert_eq!(NamingPolicy::CamelCase.apply("pet_id"), "petId");
+        assert_eq!(NamingPolicy::CamelCase.apply("pet_owner_name"), "petOwnerName");
+        assert_eq!(NamingPolicy::CamelCase.apply("petId"), "petId");
+    }
+
+    #[test]
+    fn test_naming_policy_snake_case() {
+        assert_eq!(NamingPolicy::SnakeCase.apply("petId"), "pet_id");
+        assert_eq!(NamingPolicy::SnakeCase.apply("petOwnerName"), "pet_owner_name");
+        assert_eq!(NamingPolicy::SnakeCase.apply("pet_id"), "pet_id");
+    }
+
+    #[test]
+    fn test_parse_naming_policy() {
+        assert_eq!("preserve".parse::<NamingPolicy>().unwrap(), NamingPolicy::Preserve);
+        assert_eq!("camelCase".parse::<NamingPolicy>().unwrap(), NamingPolicy::CamelCase);
+        assert_eq!("snake_case".parse::<NamingPolicy>().unwrap(), NamingPolicy::SnakeCase);
+        assert!("bogus".parse::<NamingPolicy>().is_err());
+    }
+
+    #[test]
+    fn test_naming_policy_display_roundtrips_through_parse() {
+        for policy in [NamingPolicy::Preserve, NamingPolicy::CamelCase, NamingPolicy::SnakeCase] {
+            assert_eq!(policy.to_string().parse::<NamingPolicy>().unwrap(), policy);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! `OpenAPI`-to-Luau code generation.
+
+pub mod generate;
+pub mod luau_types;
+pub mod manifest;
+pub mod parser;
+pub mod refs;
@@ -2,21 +2,30 @@ use std::path::Path;
 
 use anyhow::{Context, Result};
 use openapiv3::OpenAPI;
+use serde_json::Value;
 
-/// Load an OpenAPI spec from a local YAML or JSON file.
-pub fn load_spec_from_file(path: &Path) -> Result<OpenAPI> {
+use super::manifest::NamingPolicy;
+use super::refs::{self, RefBase};
+
+/// Load an OpenAPI spec from a local YAML or JSON file, bundling any
+/// external `$ref`s (relative file paths or remote URLs) it contains.
+pub async fn load_spec_from_file(path: &Path) -> Result<OpenAPI> {
     let content =
         std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
 
-    // Try YAML first (which is a superset of JSON), then fall back to JSON
-    let spec: OpenAPI = serde_yaml::from_str(&content)
-        .or_else(|_| serde_json::from_str(&content))
+    let mut document = parse_to_value(&content)
         .with_context(|| format!("Failed to parse OpenAPI spec from {}", path.display()))?;
 
-    Ok(spec)
+    refs::resolve_external_refs(&mut document, RefBase::for_file(path))
+        .await
+        .with_context(|| format!("Failed to resolve $refs in {}", path.display()))?;
+
+    serde_json::from_value(document)
+        .with_context(|| format!("Failed to parse OpenAPI spec from {}", path.display()))
 }
 
-/// Fetch and parse an OpenAPI spec from a URL.
+/// Fetch and parse an OpenAPI spec from a URL, bundling any external
+/// `$ref`s (relative file paths or remote URLs) it contains.
 pub async fn load_spec_from_url(url: &str) -> Result<OpenAPI> {
     let response = reqwest::get(url)
         .await
@@ -27,70 +36,114 @@ pub async fn load_spec_from_url(url: &str) -> Result<OpenAPI> {
         .await
         .with_context(|| format!("Failed to read response body from {url}"))?;
 
-    let spec: OpenAPI = serde_yaml::from_str(&content)
-        .or_else(|_| serde_json::from_str(&content))
-        .with_context(|| format!("Failed to parse OpenAPI spec from {url}"))?;
+    let mut document =
+        parse_to_value(&content).with_context(|| format!("Failed to parse OpenAPI spec from {url}"))?;
 
-    Ok(spec)
+    refs::resolve_external_refs(&mut document, RefBase::for_url(url))
+        .await
+        .with_context(|| format!("Failed to resolve $refs in {url}"))?;
+
+    serde_json::from_value(document).with_context(|| format!("Failed to parse OpenAPI spec from {url}"))
+}
+
+/// Parse spec content as YAML (a superset of JSON), falling back to JSON.
+fn parse_to_value(content: &str) -> Result<Value> {
+    serde_yaml::from_str(content)
+        .or_else(|_| serde_json::from_str(content))
+        .map_err(Into::into)
+}
+
+/// Resolve the effective [`NamingPolicy`] for `spec`: its own top-level
+/// `x-identifier-casing` vendor extension if present and valid, otherwise
+/// `default`.
+pub fn resolve_naming_policy(spec: &OpenAPI, default: NamingPolicy) -> NamingPolicy {
+    spec.extensions
+        .get("x-identifier-casing")
+        .and_then(Value::as_str)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(default)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_load_spec_from_file() {
-        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).unwrap();
+    #[tokio::test]
+    async fn test_load_spec_from_file() {
+        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).await.unwrap();
         assert_eq!(spec.info.title, "Petstore");
         assert!(!spec.paths.paths.is_empty());
     }
 
-    #[test]
-    fn test_load_spec_from_file_info() {
-        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).unwrap();
+    #[tokio::test]
+    async fn test_load_spec_from_file_info() {
+        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).await.unwrap();
         assert_eq!(spec.info.version, "1.0.0");
         assert!(spec.info.description.is_some());
     }
 
-    #[test]
-    fn test_load_spec_from_file_servers() {
-        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).unwrap();
+    #[tokio::test]
+    async fn test_load_spec_from_file_servers() {
+        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).await.unwrap();
         assert_eq!(spec.servers.len(), 1);
         assert_eq!(spec.servers[0].url, "https://petstore.example.com/v1");
     }
 
-    #[test]
-    fn test_load_spec_from_file_paths() {
-        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).unwrap();
+    #[tokio::test]
+    async fn test_load_spec_from_file_paths() {
+        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).await.unwrap();
         assert!(spec.paths.paths.contains_key("/pets"));
         assert!(spec.paths.paths.contains_key("/pets/{petId}"));
     }
 
-    #[test]
-    fn test_load_spec_from_file_schemas() {
-        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).unwrap();
+    #[tokio::test]
+    async fn test_load_spec_from_file_schemas() {
+        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).await.unwrap();
         let components = spec.components.as_ref().unwrap();
         assert!(components.schemas.contains_key("Pet"));
         assert!(components.schemas.contains_key("NewPet"));
     }
 
-    #[test]
-    fn test_load_spec_from_file_security() {
-        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).unwrap();
+    #[tokio::test]
+    async fn test_load_spec_from_file_security() {
+        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).await.unwrap();
         let components = spec.components.as_ref().unwrap();
         assert!(components.security_schemes.contains_key("bearerAuth"));
     }
 
-    #[test]
-    fn test_load_spec_from_file_tags() {
-        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).unwrap();
+    #[tokio::test]
+    async fn test_load_spec_from_file_tags() {
+        let spec = load_spec_from_file(Path::new("testdata/petstore.yaml")).await.unwrap();
         assert_eq!(spec.tags.len(), 1);
         assert_eq!(spec.tags[0].name, "pets");
     }
 
     #[test]
-    fn test_load_spec_nonexistent_file() {
-        let result = load_spec_from_file(Path::new("testdata/nonexistent.yaml"));
+    fn test_resolve_naming_policy_falls_back_to_default() {
+        let spec: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "t", "version": "1.0.0" },
+            "paths": {}
+        }))
+        .unwrap();
+        assert_eq!(resolve_naming_policy(&spec, NamingPolicy::CamelCase), NamingPolicy::CamelCase);
+    }
+
+    #[test]
+    fn test_resolve_naming_policy_uses_spec_extension() {
+        let spec: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "t", "version": "1.0.0" },
+            "paths": {},
+            "x-identifier-casing": "snake_case"
+        }))
+        .unwrap();
+        assert_eq!(resolve_naming_policy(&spec, NamingPolicy::CamelCase), NamingPolicy::SnakeCase);
+    }
+
+    #[tokio::test]
+    async fn test_load_spec_nonexistent_file() {
+        let result = load_spec_from_file(Path::new("testdata/nonexistent.yaml")).await;
         assert!(result.is_err());
     }
 }
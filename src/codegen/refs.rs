@@ -0,0 +1,488 @@
+//! Resolves external `$ref`s (relative file paths or remote URLs) found
+//! anywhere in a parsed `OpenAPI` document, inlining each referenced
+//! subschema into the root document's `components.schemas` under a
+//! deterministically generated name and rewriting the `$ref` to point at it.
+//!
+//! Internal refs (`#/components/...`) in the root document are left
+//! untouched — `openapiv3` already resolves those once the document is
+//! deserialized. But an internal ref *inside an externally-loaded document*
+//! is local to that document's own namespace, not the root's; once its
+//! enclosing schema is inlined, such a ref is rebased the same way an
+//! external ref would be — the fragment it points to is itself inlined
+//! into `components.schemas` and the ref is rewritten to match.
+
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde_json::{Map, Value};
+
+/// Where a document's relative `$ref`s resolve from.
+#[derive(Debug, Clone)]
+pub enum RefBase {
+    /// Relative file refs resolve against this directory.
+    File(PathBuf),
+    /// Relative refs resolve against this base URL.
+    Url(String),
+}
+
+impl RefBase {
+    /// The base for a local spec file, e.g. `load_spec_from_file`'s argument.
+    pub fn for_file(path: &Path) -> Self {
+        RefBase::File(path.parent().unwrap_or_else(|| Path::new(".")).to_path_buf())
+    }
+
+    /// The base for a spec fetched from a URL.
+    pub fn for_url(url: &str) -> Self {
+        RefBase::Url(url.to_string())
+    }
+}
+
+/// Documents already fetched/read during this resolution pass, keyed by
+/// normalized location (absolute path or absolute URL), so a spec split
+/// across many refs to the same file only loads it once.
+type RefCache = HashMap<String, Value>;
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// The externally-loaded document currently being inlined, if any, so that
+/// an internal `#`-fragment ref found inside it can be resolved against its
+/// own namespace rather than the root document's.
+#[derive(Clone, Copy)]
+struct SourceDoc<'a> {
+    /// Normalized location (absolute path or absolute URL) of the document,
+    /// used to key cycle detection and generated schema names the same way
+    /// external refs are.
+    location: &'a str,
+    doc: &'a Value,
+}
+
+/// Walk `document`, resolving every external `$ref`, and merge the inlined
+/// schemas into `document["components"]["schemas"]`.
+pub async fn resolve_external_refs(document: &mut Value, base: RefBase) -> Result<()> {
+    let mut cache = RefCache::new();
+    let mut visiting = Vec::new();
+    let mut new_schemas = Map::new();
+    let mut known_names = existing_schema_names(document);
+
+    resolve_in(
+        document,
+        &mut new_schemas,
+        &mut known_names,
+        &base,
+        &mut cache,
+        &mut visiting,
+        None,
+    )
+    .await?;
+
+    if new_schemas.is_empty() {
+        return Ok(());
+    }
+
+    let root = document
+        .as_object_mut()
+        .context("OpenAPI document root must be an object")?;
+    let components = root
+        .entry("components")
+        .or_insert_with(|| Value::Object(Map::new()));
+    let schemas = components
+        .as_object_mut()
+        .context("'components' must be an object")?
+        .entry("schemas")
+        .or_insert_with(|| Value::Object(Map::new()));
+    schemas
+        .as_object_mut()
+        .context("'components.schemas' must be an object")?
+        .extend(new_schemas);
+
+    Ok(())
+}
+
+fn existing_schema_names(document: &Value) -> HashSet<String> {
+    document
+        .pointer("/components/schemas")
+        .and_then(Value::as_object)
+        .map(|schemas| schemas.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+fn resolve_in<'a>(
+    node: &'a mut Value,
+    new_schemas: &'a mut Map<String, Value>,
+    known_names: &'a mut HashSet<String>,
+    base: &'a RefBase,
+    cache: &'a mut RefCache,
+    visiting: &'a mut Vec<String>,
+    source: Option<SourceDoc<'a>>,
+) -> BoxFuture<'a, Result<()>> {
+    Box::pin(async move {
+        let reference = match node {
+            Value::Object(map) => map.get("$ref").and_then(Value::as_str).map(String::from),
+            _ => None,
+        };
+
+        if let Some(reference) = &reference {
+            // An internal ref in the root document resolves against the
+            // root itself; `openapiv3` handles that once the document is
+            // deserialized, so leave it as-is.
+            let is_root_internal_ref = reference.starts_with('#') && source.is_none();
+
+            if !is_root_internal_ref {
+                let name = match reference.strip_prefix('#') {
+                    // An internal ref inside an externally-loaded document
+                    // points into that document's own namespace; inline it
+                    // the same way an external ref would be.
+                    Some(fragment) => {
+                        inline_internal_ref(
+                            fragment,
+                            source.expect("checked above"),
+                            new_schemas,
+                            known_names,
+                            base,
+                            cache,
+                            visiting,
+                        )
+                        .await?
+                    }
+                    None => inline_external_ref(reference, new_schemas, known_names, base, cache, visiting).await?,
+                };
+
+                if let Value::Object(map) = node {
+                    map.insert(
+                        "$ref".to_string(),
+                        Value::String(format!("#/components/schemas/{name}")),
+                    );
+                }
+                return Ok(());
+            }
+        }
+
+        match node {
+            Value::Object(map) => {
+                for value in map.values_mut() {
+                    resolve_in(value, new_schemas, known_names, base, cache, visiting, source).await?;
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    resolve_in(item, new_schemas, known_names, base, cache, visiting, source).await?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    })
+}
+
+/// Load, resolve, and inline the subschema referenced by an external
+/// `$ref` string, returning the generated component name it was placed
+/// under.
+async fn inline_external_ref<'a>(
+    reference: &str,
+    new_schemas: &'a mut Map<String, Value>,
+    known_names: &'a mut HashSet<String>,
+    base: &'a RefBase,
+    cache: &'a mut RefCache,
+    visiting: &'a mut Vec<String>,
+) -> Result<String> {
+    let (location_part, fragment) = match reference.split_once('#') {
+        Some((loc, frag)) => (loc, frag),
+        None => (reference, ""),
+    };
+
+    let (location, is_url) = resolve_location(base, location_part)?;
+    let cycle_key = format!("{location}#{fragment}");
+
+    if visiting.contains(&cycle_key) {
+        bail!(
+            "cyclic $ref detected: {} -> {cycle_key}",
+            visiting.join(" -> ")
+        );
+    }
+
+    let remote_doc = load_document(&location, is_url, cache).await?;
+    let target = remote_doc
+        .pointer(fragment)
+        .cloned()
+        .ok_or_else(|| anyhow!("JSON pointer '{fragment}' not found in {location}"))?;
+
+    let name = schema_name_for(fragment, &location, known_names);
+    known_names.insert(name.clone());
+
+    let nested_base = if is_url {
+        RefBase::Url(location.clone())
+    } else {
+        RefBase::for_file(Path::new(&location))
+    };
+
+    let mut target = target;
+    let source = SourceDoc {
+        location: &location,
+        doc: &remote_doc,
+    };
+    visiting.push(cycle_key);
+    resolve_in(
+        &mut target,
+        new_schemas,
+        known_names,
+        &nested_base,
+        cache,
+        visiting,
+        Some(source),
+    )
+    .await?;
+    visiting.pop();
+
+    new_schemas.insert(name.clone(), target);
+    Ok(name)
+}
+
+/// Inline the subschema at `fragment` within `source`'s own document,
+/// returning the generated component name it was placed under. Used to
+/// rebase an internal `#`-ref found inside an externally-loaded document,
+/// whose fragment addresses that document's namespace, not the root's.
+async fn inline_internal_ref<'a>(
+    fragment: &str,
+    source: SourceDoc<'a>,
+    new_schemas: &'a mut Map<String, Value>,
+    known_names: &'a mut HashSet<String>,
+    base: &'a RefBase,
+    cache: &'a mut RefCache,
+    visiting: &'a mut Vec<String>,
+) -> Result<String> {
+    let cycle_key = format!("{}#{fragment}", source.location);
+
+    if visiting.contains(&cycle_key) {
+        bail!(
+            "cyclic $ref detected: {} -> {cycle_key}",
+            visiting.join(" -> ")
+        );
+    }
+
+    let mut target = source
+        .doc
+        .pointer(fragment)
+        .cloned()
+        .ok_or_else(|| anyhow!("JSON pointer '{fragment}' not found in {}", source.location))?;
+
+    let name = schema_name_for(fragment, source.location, known_names);
+    known_names.insert(name.clone());
+
+    visiting.push(cycle_key);
+    resolve_in(&mut target, new_schemas, known_names, base, cache, visiting, Some(source)).await?;
+    visiting.pop();
+
+    new_schemas.insert(name.clone(), target);
+    Ok(name)
+}
+
+/// Resolve a `$ref` location part against `base`, returning the normalized
+/// location (absolute path or absolute URL) and whether it's a URL.
+fn resolve_location(base: &RefBase, location: &str) -> Result<(String, bool)> {
+    if location.starts_with("http://") || location.starts_with("https://") {
+        let url = reqwest::Url::parse(location).with_context(|| format!("Invalid $ref URL '{location}'"))?;
+        return Ok((url.to_string(), true));
+    }
+
+    match base {
+        RefBase::Url(base_url) => {
+            let joined = reqwest::Url::parse(base_url)
+                .with_context(|| format!("Invalid base URL '{base_url}'"))?
+                .join(location)
+                .with_context(|| format!("Failed to resolve '{location}' against '{base_url}'"))?;
+            Ok((joined.to_string(), true))
+        }
+        RefBase::File(dir) => {
+            let joined = dir.join(location);
+            let path = std::fs::canonicalize(&joined).unwrap_or(joined);
+            Ok((path.to_string_lossy().into_owned(), false))
+        }
+    }
+}
+
+async fn load_document(location: &str, is_url: bool, cache: &mut RefCache) -> Result<Value> {
+    if let Some(doc) = cache.get(location) {
+        return Ok(doc.clone());
+    }
+
+    let content = if is_url {
+        reqwest::get(location)
+            .await
+            .with_context(|| format!("Failed to fetch $ref target {location}"))?
+            .text()
+            .await
+            .with_context(|| format!("Failed to read $ref target {location}"))?
+    } else {
+        std::fs::read_to_string(location)
+            .with_context(|| format!("Failed to read $ref target {location}"))?
+    };
+
+    let value: Value = serde_yaml::from_str(&content)
+        .or_else(|_| serde_json::from_str(&content))
+        .with_context(|| format!("Failed to parse $ref target {location}"))?;
+
+    cache.insert(location.to_string(), value.clone());
+    Ok(value)
+}
+
+/// Derive a deterministic, collision-free component name for an inlined
+/// schema from its JSON-pointer fragment (preferred) or source file name.
+fn schema_name_for(fragment: &str, location: &str, known_names: &HashSet<String>) -> String {
+    let base_name = fragment
+        .rsplit('/')
+        .find(|segment| !segment.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| {
+            Path::new(location)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("Schema")
+                .to_string()
+        });
+
+    if !known_names.contains(&base_name) {
+        return base_name;
+    }
+
+    let mut suffix = 2;
+    loop {
+        let candidate = format!("{base_name}_{suffix}");
+        if !known_names.contains(&candidate) {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resolves_relative_file_ref() {
+        let dir = std::env::temp_dir().join(format!("code-mcp-refs-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("pet.yaml"),
+            "Pet:\n  type: object\n  properties:\n    name:\n      type: string\n",
+        )
+        .unwrap();
+
+        let mut document = serde_json::json!({
+            "openapi": "3.0.0",
+            "paths": {
+                "/pets": {
+                    "get": {
+                        "responses": {
+                            "200": {
+                                "description": "ok",
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "$ref": "./pet.yaml#/Pet" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        resolve_external_refs(&mut document, RefBase::File(dir.clone()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            document.pointer("/components/schemas/Pet/type"),
+            Some(&Value::String("object".to_string()))
+        );
+        assert_eq!(
+            document.pointer("/paths/~1pets/get/responses/200/content/application~1json/schema/$ref"),
+            Some(&Value::String("#/components/schemas/Pet".to_string()))
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_rebases_internal_ref_inside_external_document() {
+        let dir = std::env::temp_dir().join(format!("code-mcp-refs-internal-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("pet.yaml"),
+            "Pet:\n  type: object\n  properties:\n    owner:\n      $ref: \"#/Address\"\n\
+             Address:\n  type: object\n  properties:\n    city:\n      type: string\n",
+        )
+        .unwrap();
+
+        let mut document = serde_json::json!({
+            "openapi": "3.0.0",
+            "paths": {
+                "/pets": {
+                    "get": {
+                        "responses": {
+                            "200": {
+                                "description": "ok",
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "$ref": "./pet.yaml#/Pet" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        resolve_external_refs(&mut document, RefBase::File(dir.clone()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            document.pointer("/components/schemas/Pet/properties/owner/$ref"),
+            Some(&Value::String("#/components/schemas/Address".to_string()))
+        );
+        assert_eq!(
+            document.pointer("/components/schemas/Address/type"),
+            Some(&Value::String("object".to_string()))
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_cyclic_ref_errors() {
+        let dir = std::env::temp_dir().join(format!("code-mcp-refs-cycle-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("a.yaml"),
+            "A:\n  \"$ref\": \"./b.yaml#/B\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("b.yaml"),
+            "B:\n  \"$ref\": \"./a.yaml#/A\"\n",
+        )
+        .unwrap();
+
+        let mut document = serde_json::json!({ "schema": { "$ref": "./a.yaml#/A" } });
+
+        let result = resolve_external_refs(&mut document, RefBase::File(dir.clone())).await;
+        assert!(result.is_err());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_schema_name_dedup() {
+        let mut known = HashSet::new();
+        known.insert("Pet".to_string());
+        assert_eq!(schema_name_for("/Pet", "pet.yaml", &known), "Pet_2");
+        assert_eq!(schema_name_for("", "pet.yaml", &HashSet::new()), "pet");
+    }
+}
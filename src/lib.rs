@@ -0,0 +1,6 @@
+//! Library crate for `code-mcp`: generates and serves MCP servers from
+//! `OpenAPI` specs.
+
+pub mod auth;
+pub mod codegen;
+pub mod serve;
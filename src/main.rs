@@ -1,14 +1,20 @@
 mod cli;
 
+use std::time::Duration;
+
 use clap::Parser;
 use cli::{Cli, Command};
+use code_mcp::auth::AuthConfig;
+use code_mcp::codegen::manifest::NamingPolicy;
+use code_mcp::serve::{McpServer, SandboxLimits, Transport, UnknownProperties};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
     match cli.command {
-        Command::Generate { specs, output } => {
-            code_mcp::codegen::generate::generate(&specs, &output)?;
+        Command::Generate { specs, output, naming } => {
+            let naming: NamingPolicy = naming.parse()?;
+            code_mcp::codegen::generate::generate(&specs, &output, naming).await?;
             println!("Generated output to {}", output.display());
             Ok(())
         }
@@ -16,17 +22,68 @@ async fn main() -> anyhow::Result<()> {
             dir,
             transport,
             port,
+            auth_authority,
+            auth_audience,
+            auth_jwks_uri,
+            auth_algorithms,
+            timeout,
+            memory_limit,
+            max_api_calls,
+            unknown_properties,
         } => {
-            println!("Serve: {:?} ({} on {})", dir, transport, port);
-            todo!("serve command")
+            let transport: Transport = transport.parse()?;
+            let limits = sandbox_limits(timeout, memory_limit, max_api_calls);
+            let auth = AuthConfig::from_flags(auth_authority, auth_audience, auth_jwks_uri, auth_algorithms)?;
+            let unknown_properties: UnknownProperties = unknown_properties.parse()?;
+            let server = McpServer::load(&dir, limits, auth, unknown_properties)?;
+            server.serve(transport, port).await
         }
         Command::Run {
             specs,
+            naming,
             transport,
             port,
+            auth_authority,
+            auth_audience,
+            auth_jwks_uri,
+            auth_algorithms,
+            timeout,
+            memory_limit,
+            max_api_calls,
+            unknown_properties,
         } => {
-            println!("Run: {:?} ({} on {})", specs, transport, port);
-            todo!("run command")
+            let output = std::env::temp_dir().join(format!("code-mcp-run-{}", std::process::id()));
+            let naming: NamingPolicy = naming.parse()?;
+            code_mcp::codegen::generate::generate(&specs, &output, naming).await?;
+
+            let transport: Transport = transport.parse()?;
+            let limits = sandbox_limits(timeout, memory_limit, max_api_calls);
+            let auth = AuthConfig::from_flags(auth_authority, auth_audience, auth_jwks_uri, auth_algorithms)?;
+            let unknown_properties: UnknownProperties = unknown_properties.parse()?;
+            let server = McpServer::load(&output, limits, auth, unknown_properties)?;
+            server.serve(transport, port).await
         }
+        Command::Describe {
+            dir,
+            timeout,
+            memory_limit,
+            max_api_calls,
+            unknown_properties,
+        } => {
+            let limits = sandbox_limits(timeout, memory_limit, max_api_calls);
+            let unknown_properties: UnknownProperties = unknown_properties.parse()?;
+            let server = McpServer::load(&dir, limits, None, unknown_properties)?;
+            let description = server.describe(&[Transport::Stdio, Transport::Sse, Transport::Http]);
+            println!("{}", serde_json::to_string_pretty(&description)?);
+            Ok(())
+        }
+    }
+}
+
+fn sandbox_limits(timeout_secs: u64, memory_limit_mb: usize, max_api_calls: usize) -> SandboxLimits {
+    SandboxLimits {
+        timeout: Duration::from_secs(timeout_secs),
+        memory_limit_mb,
+        max_api_calls,
     }
 }
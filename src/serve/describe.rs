@@ -0,0 +1,38 @@
+//! The `describe` subcommand and MCP handshake server-info payload.
+
+use serde::Serialize;
+
+use super::transport::Transport;
+use super::McpServer;
+
+/// Reports server identity, registered-tool count, supported transports,
+/// and the sandbox limits in effect — the same payload whether asked for
+/// via `code-mcp describe` or an MCP `initialize` handshake. `transports`
+/// lists whatever the caller passes: `describe` passes all three, while a
+/// handshake lists only the transport it arrived on.
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerDescription {
+    pub name: String,
+    pub version: String,
+    pub tool_count: usize,
+    pub transports: Vec<String>,
+    pub timeout_secs: u64,
+    pub memory_limit_mb: usize,
+    pub max_api_calls: usize,
+    pub unknown_properties: String,
+}
+
+impl ServerDescription {
+    pub fn new(server: &McpServer, transports: &[Transport]) -> Self {
+        Self {
+            name: server.name.clone(),
+            version: server.version.clone(),
+            tool_count: server.tools.len(),
+            transports: transports.iter().map(Transport::to_string).collect(),
+            timeout_secs: server.limits.timeout.as_secs(),
+            memory_limit_mb: server.limits.memory_limit_mb,
+            max_api_calls: server.limits.max_api_calls,
+            unknown_properties: server.unknown_properties.to_string(),
+        }
+    }
+}
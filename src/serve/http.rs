@@ -0,0 +1,34 @@
+//! Streamable-HTTP transport: a single `POST /mcp` endpoint that accepts one
+//! JSON-RPC request body and returns one JSON-RPC response as the JSON
+//! response body.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::routing::post;
+use axum::{Json, Router};
+
+use super::protocol::{self, bearer_token, JsonRpcRequest, JsonRpcResponse};
+use super::transport::Transport;
+use super::McpServer;
+
+async fn handle_mcp(
+    State(server): State<Arc<McpServer>>,
+    headers: HeaderMap,
+    Json(request): Json<JsonRpcRequest>,
+) -> Json<JsonRpcResponse> {
+    let token = bearer_token(&headers);
+    Json(protocol::dispatch(&server, &[Transport::Http], token.as_deref(), request).await)
+}
+
+pub async fn serve(server: McpServer, port: u16) -> Result<()> {
+    let app = Router::new()
+        .route("/mcp", post(handle_mcp))
+        .with_state(Arc::new(server));
+
+    let listener = tokio::net::TcpListener::bind(("0.0.0.0", port)).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! Executes a single Luau tool script inside a fresh VM, wired to the
+//! current invocation's [`ApiCallBudget`] and memory limit.
+
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use mlua::{Lua, LuaSerdeExt, VmState};
+use serde_json::Value;
+
+use super::sandbox::{ApiCallBudget, SandboxLimits};
+
+/// Load `script_path` and invoke its top-level handler function with
+/// `input`, returning the JSON value it produces.
+///
+/// The script's `api.call(...)` binding consumes one unit of `budget` per
+/// call; once exhausted, further calls fail inside the VM rather than
+/// reaching the network. An interrupt hook checked against `limits.timeout`
+/// aborts execution from inside the VM once the deadline passes, so a
+/// CPU-bound script (one that never yields back to `api.call`) is actually
+/// stopped rather than merely abandoned by the caller.
+pub fn execute(
+    script_path: &Path,
+    input: &Value,
+    limits: &SandboxLimits,
+    budget: &ApiCallBudget,
+) -> Result<Value> {
+    let source = std::fs::read_to_string(script_path)
+        .with_context(|| format!("Failed to read tool script {}", script_path.display()))?;
+
+    let lua = Lua::new();
+    lua.set_memory_limit(limits.memory_limit_mb * 1024 * 1024)
+        .context("Failed to set Luau VM memory limit")?;
+
+    let deadline = Instant::now() + limits.timeout;
+    let timeout_secs = limits.timeout.as_secs();
+    lua.set_interrupt(move |_| {
+        if Instant::now() >= deadline {
+            Err(mlua::Error::RuntimeError(format!(
+                "tool execution exceeded the {timeout_secs}s timeout"
+            )))
+        } else {
+            Ok(VmState::Continue)
+        }
+    });
+
+    let call_budget = budget.clone();
+    let api = lua.create_table()?;
+    api.set(
+        "call",
+        lua.create_function(move |_, ()| {
+            call_budget
+                .consume()
+                .map_err(|e| mlua::Error::RuntimeError(e.to_string()))
+        })?,
+    )?;
+    lua.globals().set("api", api)?;
+
+    let input_value = lua.to_value(input)?;
+    let handler: mlua::Function = lua
+        .load(&source)
+        .eval()
+        .with_context(|| format!("Failed to load tool script {}", script_path.display()))?;
+    let result = handler.call::<_, mlua::Value>(input_value)?;
+    let json_result: Value = lua.from_value(result)?;
+    Ok(json_result)
+}
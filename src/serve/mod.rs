@@ -0,0 +1,79 @@
+//! The MCP server runtime: loads a generated output directory and serves
+//! its tools over stdio, SSE, or streamable HTTP.
+
+mod describe;
+mod http;
+mod luau_vm;
+mod protocol;
+mod sandbox;
+mod sse;
+mod stdio;
+mod tool;
+mod transport;
+mod validate;
+
+use std::path::Path;
+
+use anyhow::Result;
+
+pub use describe::ServerDescription;
+pub use sandbox::SandboxLimits;
+pub use transport::Transport;
+pub use validate::UnknownProperties;
+
+use crate::auth::AuthConfig;
+use tool::RegisteredTool;
+
+/// A loaded MCP server: its registered tools, the sandbox limits every
+/// invocation runs under, its optional auth configuration, and how it
+/// treats unknown properties in tool-call arguments.
+pub struct McpServer {
+    pub name: String,
+    pub version: String,
+    pub tools: Vec<RegisteredTool>,
+    pub limits: SandboxLimits,
+    pub auth: Option<AuthConfig>,
+    pub unknown_properties: UnknownProperties,
+}
+
+impl McpServer {
+    /// Load a generated output directory (`manifest.json` plus Luau tool
+    /// scripts) into a runnable server.
+    pub fn load(
+        dir: &Path,
+        limits: SandboxLimits,
+        auth: Option<AuthConfig>,
+        unknown_properties: UnknownProperties,
+    ) -> Result<Self> {
+        let tools = tool::load_tools(dir)?;
+        let name = dir
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("mcp-server")
+            .to_string();
+
+        Ok(Self {
+            name,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            tools,
+            limits,
+            auth,
+            unknown_properties,
+        })
+    }
+
+    /// Produce the introspection payload used by the `describe` subcommand
+    /// and the MCP `initialize` handshake.
+    pub fn describe(&self, transports: &[Transport]) -> ServerDescription {
+        ServerDescription::new(self, transports)
+    }
+
+    /// Serve this server over `transport`, blocking until shutdown.
+    pub async fn serve(self, transport: Transport, port: u16) -> Result<()> {
+        match transport {
+            Transport::Stdio => stdio::serve(self).await,
+            Transport::Sse => sse::serve(self, port).await,
+            Transport::Http => http::serve(self, port).await,
+        }
+    }
+}
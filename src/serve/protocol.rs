@@ -0,0 +1,231 @@
+//! Minimal MCP JSON-RPC message types and the request dispatcher shared by
+//! all three transports.
+
+use axum::http::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::auth::scopes;
+use crate::codegen::luau_types;
+use super::sandbox::{self, ApiCallBudget};
+use super::transport::Transport;
+use super::validate::{self, ValidationError};
+use super::{luau_vm, McpServer};
+
+/// Extract the bearer token from an `Authorization: Bearer <token>` header,
+/// if present.
+pub fn bearer_token(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::to_string)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JsonRpcRequest {
+    pub id: Value,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonRpcResponse {
+    pub jsonrpc: &'static str,
+    pub id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<JsonRpcError>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl JsonRpcResponse {
+    fn ok(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    fn err(id: Value, message: impl Into<String>) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: None,
+            error: Some(JsonRpcError {
+                code: -32000,
+                message: message.into(),
+                data: None,
+            }),
+        }
+    }
+
+    /// An authorization failure: a missing bearer token, a JWT that fails
+    /// validation, or a token whose scopes don't cover what the tool
+    /// requires. `data.missing_scopes` lists the scopes the caller lacks,
+    /// if any.
+    fn unauthorized(id: Value, message: impl Into<String>, missing_scopes: Vec<String>) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: None,
+            error: Some(JsonRpcError {
+                code: -32001,
+                message: message.into(),
+                data: Some(serde_json::json!({ "missing_scopes": missing_scopes })),
+            }),
+        }
+    }
+
+    /// The standard JSON-RPC "Invalid params" error, carrying every schema
+    /// violation found in the call's arguments as the `data` array (one
+    /// `{pointer, reason}` object per violation).
+    fn invalid_params(id: Value, errors: &[ValidationError]) -> Self {
+        let data = errors
+            .iter()
+            .map(|e| serde_json::json!({ "pointer": e.pointer, "reason": e.reason }))
+            .collect::<Vec<_>>();
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: None,
+            error: Some(JsonRpcError {
+                code: -32602,
+                message: "tool arguments failed schema validation".to_string(),
+                data: Some(Value::Array(data)),
+            }),
+        }
+    }
+}
+
+/// Handle one JSON-RPC request against `server`, honoring the sandbox
+/// limits and (if configured) the auth requirements of the tool being
+/// called. `token` is the bearer token presented by the caller, if any.
+pub async fn dispatch(
+    server: &McpServer,
+    transports: &[Transport],
+    token: Option<&str>,
+    request: JsonRpcRequest,
+) -> JsonRpcResponse {
+    match request.method.as_str() {
+        "initialize" => {
+            let description = server.describe(transports);
+            match serde_json::to_value(description) {
+                Ok(result) => JsonRpcResponse::ok(request.id, result),
+                Err(e) => JsonRpcResponse::err(request.id, e.to_string()),
+            }
+        }
+        "tools/list" => {
+            let tools: Vec<_> = server
+                .tools
+                .iter()
+                .map(|t| {
+                    serde_json::json!({
+                        "name": t.name,
+                        "description": t.description,
+                        "inputSchema": t.input_schema,
+                    })
+                })
+                .collect();
+            JsonRpcResponse::ok(request.id, serde_json::json!({ "tools": tools }))
+        }
+        "tools/call" => call_tool(server, token, request).await,
+        other => JsonRpcResponse::err(request.id, format!("unknown method '{other}'")),
+    }
+}
+
+async fn call_tool(server: &McpServer, token: Option<&str>, request: JsonRpcRequest) -> JsonRpcResponse {
+    let Some(name) = request.params.get("name").and_then(Value::as_str) else {
+        return JsonRpcResponse::err(request.id, "missing required 'name' parameter");
+    };
+
+    let Some(tool) = server.tools.iter().find(|t| t.name == name) else {
+        return JsonRpcResponse::err(request.id, format!("unknown tool '{name}'"));
+    };
+
+    if let Some(auth) = &server.auth {
+        if tool.auth_required {
+            let closest_missing = |granted: &[String]| {
+                tool.required_scopes
+                    .iter()
+                    .map(|alternative| scopes::missing_scopes(alternative, granted))
+                    .min_by_key(Vec::len)
+                    .unwrap_or_default()
+            };
+
+            let Some(token) = token else {
+                return JsonRpcResponse::unauthorized(request.id, "missing bearer token", closest_missing(&[]));
+            };
+
+            let granted = match crate::auth::jwt::validate(auth, token).await {
+                Ok(granted) => granted,
+                Err(e) => return JsonRpcResponse::unauthorized(request.id, e.to_string(), closest_missing(&[])),
+            };
+
+            if !scopes::is_authorized(&tool.required_scopes, &granted) {
+                let missing = closest_missing(&granted);
+                return JsonRpcResponse::unauthorized(
+                    request.id,
+                    format!("token is missing required scope(s): {}", missing.join(", ")),
+                    missing,
+                );
+            }
+        }
+    }
+
+    let input = request
+        .params
+        .get("arguments")
+        .cloned()
+        .unwrap_or_else(|| Value::Object(serde_json::Map::new()));
+
+    let schema_errors = validate::validate(&tool.input_schema, &input, server.unknown_properties);
+    if !schema_errors.is_empty() {
+        return JsonRpcResponse::invalid_params(request.id, &schema_errors);
+    }
+
+    // `input` is keyed by the wire names declared in `tool.input_schema`;
+    // re-key it to the Luau identifiers the script was generated against
+    // before handing it to the VM.
+    let input = luau_types::rename_params_to_luau(&input, &tool.params);
+
+    let limits = server.limits;
+    let budget = ApiCallBudget::new(&limits);
+    let script_path = tool.script_path.clone();
+
+    let result = sandbox::run_with_limits(&limits, async move {
+        tokio::task::spawn_blocking(move || luau_vm::execute(&script_path, &input, &limits, &budget))
+            .await
+            .map_err(anyhow::Error::from)?
+    })
+    .await;
+
+    match result {
+        Ok(value) => JsonRpcResponse::ok(request.id, call_tool_result(&value)),
+        Err(e) => JsonRpcResponse::err(request.id, e.to_string()),
+    }
+}
+
+/// Wrap a tool script's return value in the MCP `tools/call` result shape:
+/// a list of content items (here always one `text` item) plus `isError`.
+fn call_tool_result(value: &Value) -> Value {
+    let text = match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    serde_json::json!({
+        "content": [{ "type": "text", "text": text }],
+        "isError": false,
+    })
+}
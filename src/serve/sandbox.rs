@@ -0,0 +1,105 @@
+//! Enforces the per-invocation resource limits (timeout, memory, API-call
+//! budget) that `--timeout`, `--memory-limit`, and `--max-api-calls` control
+//! around a single Luau tool execution.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use serde_json::Value;
+
+/// Resource limits applied to every Luau tool invocation.
+#[derive(Debug, Clone, Copy)]
+pub struct SandboxLimits {
+    pub timeout: Duration,
+    pub memory_limit_mb: usize,
+    pub max_api_calls: usize,
+}
+
+/// Tracks how many outbound API calls a single invocation has made so far,
+/// so the Luau VM's `api.call` binding can be rejected once `max_api_calls`
+/// is exhausted.
+#[derive(Debug, Clone)]
+pub struct ApiCallBudget {
+    remaining: Arc<AtomicUsize>,
+}
+
+impl ApiCallBudget {
+    pub fn new(limits: &SandboxLimits) -> Self {
+        Self {
+            remaining: Arc::new(AtomicUsize::new(limits.max_api_calls)),
+        }
+    }
+
+    /// Consume one call from the budget, failing once it's exhausted.
+    pub fn consume(&self) -> Result<()> {
+        loop {
+            let current = self.remaining.load(Ordering::SeqCst);
+            if current == 0 {
+                bail!("tool exceeded its max-api-calls budget");
+            }
+            if self
+                .remaining
+                .compare_exchange(current, current - 1, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+            {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Run a single tool invocation under `limits`.
+///
+/// This only bounds how long the caller *waits*: the actual abort of a
+/// CPU-bound Luau script happens inside the VM itself, via the interrupt
+/// hook [`super::luau_vm::execute`] installs from the same `limits.timeout`
+/// deadline. This `tokio::time::timeout` is a backstop so the caller isn't
+/// left waiting past the deadline even if, for some reason, the blocking
+/// task is slow to notice.
+pub async fn run_with_limits<F>(limits: &SandboxLimits, invoke: F) -> Result<Value>
+where
+    F: std::future::Future<Output = Result<Value>>,
+{
+    match tokio::time::timeout(limits.timeout, invoke).await {
+        Ok(result) => result,
+        Err(_) => bail!(
+            "tool execution exceeded the {}s timeout",
+            limits.timeout.as_secs()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget_rejects_once_exhausted() {
+        let limits = SandboxLimits {
+            timeout: Duration::from_secs(1),
+            memory_limit_mb: 64,
+            max_api_calls: 2,
+        };
+        let budget = ApiCallBudget::new(&limits);
+        assert!(budget.consume().is_ok());
+        assert!(budget.consume().is_ok());
+        assert!(budget.consume().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_with_limits_times_out() {
+        let limits = SandboxLimits {
+            timeout: Duration::from_millis(10),
+            memory_limit_mb: 64,
+            max_api_calls: 1,
+        };
+        let result = run_with_limits(&limits, async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok(Value::Null)
+        })
+        .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,52 @@
+//! SSE transport: clients open a `GET /sse` stream (which only ever emits a
+//! one-shot `ready` event, to confirm the connection is live) and `POST`
+//! JSON-RPC requests to `/message`, receiving the JSON-RPC response
+//! directly in that POST's body. Despite the transport's name, responses
+//! are not currently pushed down the SSE stream itself.
+
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, Sse};
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use futures::stream::{self, Stream, StreamExt};
+
+use super::protocol::{self, bearer_token, JsonRpcRequest};
+use super::transport::Transport;
+use super::McpServer;
+
+/// Dispatch one JSON-RPC request, returning the response directly in the
+/// POST body (see the module doc for why this isn't pushed over SSE).
+async fn handle_message(
+    State(server): State<Arc<McpServer>>,
+    headers: HeaderMap,
+    Json(request): Json<JsonRpcRequest>,
+) -> impl IntoResponse {
+    let token = bearer_token(&headers);
+    let response = protocol::dispatch(&server, &[Transport::Sse], token.as_deref(), request).await;
+    Json(response)
+}
+
+/// The `GET /sse` stream: a single `ready` event confirming the connection
+/// is live, then idle. No further events are ever sent on it.
+async fn handle_sse() -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let keepalive =
+        stream::once(async { Ok(Event::default().event("ready").data("ready")) }).chain(stream::pending());
+    Sse::new(keepalive)
+}
+
+pub async fn serve(server: McpServer, port: u16) -> Result<()> {
+    let app = Router::new()
+        .route("/sse", get(handle_sse))
+        .route("/message", post(handle_message))
+        .with_state(Arc::new(server));
+
+    let listener = tokio::net::TcpListener::bind(("0.0.0.0", port)).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
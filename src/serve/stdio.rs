@@ -0,0 +1,39 @@
+//! stdio transport: newline-delimited JSON-RPC over stdin/stdout.
+
+use anyhow::Result;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+use super::protocol::{self, JsonRpcRequest};
+use super::transport::Transport;
+use super::McpServer;
+
+pub async fn serve(server: McpServer) -> Result<()> {
+    let stdin = tokio::io::stdin();
+    let mut stdout = tokio::io::stdout();
+    let mut lines = BufReader::new(stdin).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<JsonRpcRequest>(&line) {
+            // stdio has no bearer-token channel; when auth is configured,
+            // tools that require auth are unreachable over it.
+            Ok(request) => protocol::dispatch(&server, &[Transport::Stdio], None, request).await,
+            Err(e) => {
+                stdout
+                    .write_all(format!("{{\"jsonrpc\":\"2.0\",\"error\":{{\"code\":-32700,\"message\":\"{e}\"}}}}\n").as_bytes())
+                    .await?;
+                continue;
+            }
+        };
+
+        let mut payload = serde_json::to_vec(&response)?;
+        payload.push(b'\n');
+        stdout.write_all(&payload).await?;
+        stdout.flush().await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+//! Loading registered tools (and their Luau scripts) from a generated output
+//! directory.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::codegen::manifest::McpParamDef;
+
+/// A single tool read from `manifest.json`, paired with the path to its
+/// Luau script on disk.
+#[derive(Debug, Clone)]
+pub struct RegisteredTool {
+    pub name: String,
+    pub description: Option<String>,
+    pub params: Vec<McpParamDef>,
+    pub script_path: PathBuf,
+    /// Whether the operation declares a `security` requirement at all —
+    /// gates whether a bearer token is mandatory, independent of whether
+    /// `required_scopes` names any specific scope.
+    pub auth_required: bool,
+    /// OAuth2 scope alternatives a caller's token must satisfy one of to
+    /// invoke this tool, derived from the operation's `security`
+    /// requirements. See [`crate::auth::scopes::is_authorized`].
+    pub required_scopes: Vec<Vec<String>>,
+    /// The JSON Schema arguments must satisfy, checked at call time before
+    /// the Luau script runs.
+    pub input_schema: Value,
+}
+
+#[derive(Deserialize)]
+struct ManifestFile {
+    tools: Vec<ManifestTool>,
+}
+
+#[derive(Deserialize)]
+struct ManifestTool {
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    params: Vec<ManifestParam>,
+    script: String,
+    #[serde(default)]
+    auth_required: bool,
+    #[serde(default)]
+    required_scopes: Vec<Vec<String>>,
+    #[serde(default = "empty_schema")]
+    input_schema: Value,
+}
+
+fn empty_schema() -> Value {
+    serde_json::json!({})
+}
+
+#[derive(Deserialize)]
+struct ManifestParam {
+    /// The original `OpenAPI`/JSON Schema property name; defaults to `name`
+    /// for manifests generated under [`crate::codegen::manifest::NamingPolicy::Preserve`].
+    #[serde(default)]
+    wire_name: Option<String>,
+    name: String,
+    luau_type: String,
+    #[serde(default)]
+    required: bool,
+    description: Option<String>,
+}
+
+/// Load every tool declared in `dir/manifest.json`, resolving each script
+/// path relative to `dir`.
+pub fn load_tools(dir: &Path) -> Result<Vec<RegisteredTool>> {
+    let manifest_path = dir.join("manifest.json");
+    let content = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("Failed to read {}", manifest_path.display()))?;
+    let manifest: ManifestFile = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", manifest_path.display()))?;
+
+    Ok(manifest
+        .tools
+        .into_iter()
+        .map(|tool| RegisteredTool {
+            name: tool.name,
+            description: tool.description,
+            params: tool
+                .params
+                .into_iter()
+                .map(|p| McpParamDef {
+                    wire_name: p.wire_name.unwrap_or_else(|| p.name.clone()),
+                    name: p.name,
+                    luau_type: p.luau_type,
+                    required: p.required,
+                    description: p.description,
+                })
+                .collect(),
+            script_path: dir.join(tool.script),
+            auth_required: tool.auth_required,
+            required_scopes: tool.required_scopes,
+            input_schema: tool.input_schema,
+        })
+        .collect())
+}
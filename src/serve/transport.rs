@@ -0,0 +1,65 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error};
+
+/// An MCP transport the server can be reached over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    /// JSON-RPC messages over stdin/stdout, newline-delimited.
+    Stdio,
+    /// Server-Sent Events, one client per connection.
+    Sse,
+    /// Streamable HTTP (MCP's `POST` + chunked-response transport).
+    Http,
+}
+
+impl FromStr for Transport {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "stdio" => Ok(Transport::Stdio),
+            "sse" => Ok(Transport::Sse),
+            "http" => Ok(Transport::Http),
+            other => Err(anyhow!(
+                "unknown transport '{other}', expected one of: stdio, sse, http"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Transport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Transport::Stdio => "stdio",
+            Transport::Sse => "sse",
+            Transport::Http => "http",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_known_transports() {
+        assert_eq!("stdio".parse::<Transport>().unwrap(), Transport::Stdio);
+        assert_eq!("sse".parse::<Transport>().unwrap(), Transport::Sse);
+        assert_eq!("http".parse::<Transport>().unwrap(), Transport::Http);
+    }
+
+    #[test]
+    fn test_parse_unknown_transport_errors() {
+        assert!("carrier-pigeon".parse::<Transport>().is_err());
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for t in [Transport::Stdio, Transport::Sse, Transport::Http] {
+            assert_eq!(t.to_string().parse::<Transport>().unwrap(), t);
+        }
+    }
+}
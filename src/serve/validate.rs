@@ -0,0 +1,225 @@
+//! Validates incoming `tools/call` arguments against the tool's input JSON
+//! Schema (the same schema [`crate::codegen::luau_types::json_schema_to_params`]
+//! derives Luau parameter types from) before the Luau script runs.
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error};
+use serde_json::Value;
+
+/// How to treat object properties an input schema doesn't declare.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnknownProperties {
+    /// Fail validation if the input has a property the schema doesn't declare.
+    Reject,
+    /// Silently allow properties the schema doesn't declare.
+    Ignore,
+}
+
+impl FromStr for UnknownProperties {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "reject" => Ok(UnknownProperties::Reject),
+            "ignore" => Ok(UnknownProperties::Ignore),
+            other => Err(anyhow!("unknown unknown-properties mode '{other}', expected one of: reject, ignore")),
+        }
+    }
+}
+
+impl fmt::Display for UnknownProperties {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            UnknownProperties::Reject => "reject",
+            UnknownProperties::Ignore => "ignore",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A single schema violation, pinpointed with a JSON Pointer to the
+/// offending field (RFC 6901), e.g. `/pet/tags/0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    pub pointer: String,
+    pub reason: String,
+}
+
+/// Validate `input` against `schema`, returning every violation found.
+/// `unknown_properties` is the server-wide default applied where `schema`
+/// doesn't declare its own `additionalProperties`.
+pub fn validate(schema: &Value, input: &Value, unknown_properties: UnknownProperties) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    check(schema, input, "", unknown_properties, &mut errors);
+    errors
+}
+
+fn check(schema: &Value, value: &Value, pointer: &str, unknown: UnknownProperties, errors: &mut Vec<ValidationError>) {
+    let Some(schema) = schema.as_object() else {
+        return;
+    };
+    let child = |key: &str| format!("{pointer}/{}", key.replace('~', "~0").replace('/', "~1"));
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            errors.push(ValidationError {
+                pointer: pointer.to_string(),
+                reason: format!("value is not one of the allowed enum values: {allowed:?}"),
+            });
+            return;
+        }
+    }
+
+    if let Some(expected_type) = schema.get("type").and_then(Value::as_str) {
+        if !type_matches(expected_type, value) {
+            errors.push(ValidationError {
+                pointer: pointer.to_string(),
+                reason: format!("expected type '{expected_type}', got '{}'", json_type_name(value)),
+            });
+            return;
+        }
+    }
+
+    match value {
+        Value::Object(map) => {
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                for name in required.iter().filter_map(Value::as_str) {
+                    if !map.contains_key(name) {
+                        errors.push(ValidationError {
+                            pointer: child(name),
+                            reason: "required property is missing".to_string(),
+                        });
+                    }
+                }
+            }
+
+            let properties = schema.get("properties").and_then(Value::as_object);
+            let additional_mode = match schema.get("additionalProperties") {
+                Some(Value::Bool(false)) => UnknownProperties::Reject,
+                Some(Value::Bool(true)) => UnknownProperties::Ignore,
+                _ => unknown,
+            };
+
+            for (key, val) in map {
+                match properties.and_then(|props| props.get(key)) {
+                    Some(prop_schema) => check(prop_schema, val, &child(key), unknown, errors),
+                    None if additional_mode == UnknownProperties::Reject => errors.push(ValidationError {
+                        pointer: child(key),
+                        reason: "unknown property".to_string(),
+                    }),
+                    None => {}
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    check(item_schema, item, &format!("{pointer}/{index}"), unknown, errors);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn type_matches(expected: &str, value: &Value) -> bool {
+    match expected {
+        "string" => value.is_string(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_missing_required_property() {
+        let schema = json!({"type": "object", "required": ["name"], "properties": {"name": {"type": "string"}}});
+        let errors = validate(&schema, &json!({}), UnknownProperties::Reject);
+        assert_eq!(errors, vec![ValidationError { pointer: "/name".to_string(), reason: "required property is missing".to_string() }]);
+    }
+
+    #[test]
+    fn test_type_mismatch_reports_pointer() {
+        let schema = json!({"type": "object", "properties": {"age": {"type": "integer"}}});
+        let errors = validate(&schema, &json!({"age": "not a number"}), UnknownProperties::Reject);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].pointer, "/age");
+    }
+
+    #[test]
+    fn test_nested_object_errors_use_nested_pointer() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "pet": {"type": "object", "required": ["name"], "properties": {"name": {"type": "string"}}}
+            }
+        });
+        let errors = validate(&schema, &json!({"pet": {}}), UnknownProperties::Reject);
+        assert_eq!(errors, vec![ValidationError { pointer: "/pet/name".to_string(), reason: "required property is missing".to_string() }]);
+    }
+
+    #[test]
+    fn test_array_item_errors_use_indexed_pointer() {
+        let schema = json!({"type": "object", "properties": {"tags": {"type": "array", "items": {"type": "string"}}}});
+        let errors = validate(&schema, &json!({"tags": ["ok", 5]}), UnknownProperties::Reject);
+        assert_eq!(errors, vec![ValidationError { pointer: "/tags/1".to_string(), reason: "expected type 'string', got 'number'".to_string() }]);
+    }
+
+    #[test]
+    fn test_enum_membership() {
+        let schema = json!({"type": "object", "properties": {"status": {"enum": ["open", "closed"]}}});
+        let errors = validate(&schema, &json!({"status": "pending"}), UnknownProperties::Reject);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].pointer, "/status");
+    }
+
+    #[test]
+    fn test_unknown_property_rejected_by_default() {
+        let schema = json!({"type": "object", "properties": {"name": {"type": "string"}}});
+        let errors = validate(&schema, &json!({"name": "a", "extra": 1}), UnknownProperties::Reject);
+        assert_eq!(errors, vec![ValidationError { pointer: "/extra".to_string(), reason: "unknown property".to_string() }]);
+    }
+
+    #[test]
+    fn test_unknown_property_ignored_in_ignore_mode() {
+        let schema = json!({"type": "object", "properties": {"name": {"type": "string"}}});
+        let errors = validate(&schema, &json!({"name": "a", "extra": 1}), UnknownProperties::Ignore);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_schema_additional_properties_overrides_server_default() {
+        let schema = json!({"type": "object", "properties": {}, "additionalProperties": true});
+        let errors = validate(&schema, &json!({"extra": 1}), UnknownProperties::Reject);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_parse_unknown_properties_mode() {
+        assert_eq!("reject".parse::<UnknownProperties>().unwrap(), UnknownProperties::Reject);
+        assert_eq!("ignore".parse::<UnknownProperties>().unwrap(), UnknownProperties::Ignore);
+        assert!("bogus".parse::<UnknownProperties>().is_err());
+    }
+}